@@ -0,0 +1,19 @@
+//! Solidity Interface of the ERC-1155 token.
+pub use token::*;
+
+mod token {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    stylus_sdk::prelude::sol_interface! {
+        interface Erc1155Interface {
+            function balanceOf(address account, uint256 id) external view returns (uint256);
+            function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
+            function setApprovalForAll(address operator, bool approved) external;
+            function isApprovedForAll(address account, address operator) external view returns (bool);
+            function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes calldata data) external;
+            function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] values, bytes calldata data) external;
+        }
+    }
+}
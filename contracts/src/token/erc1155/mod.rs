@@ -21,8 +21,9 @@ use crate::utils::{
 };
 
 pub mod extensions;
-mod receiver;
-pub use receiver::IERC1155Receiver;
+pub mod interface;
+pub mod receiver;
+pub use receiver::{IERC1155Receiver, IErc1155Receiver};
 
 /// The expected value returned from [`IERC1155Receiver::on_erc_1155_received`].
 pub const SINGLE_TRANSFER_FN_SELECTOR: [u8; 4] = function_selector!(
@@ -2,9 +2,11 @@
 //! ERC-1155 token transfers.
 #![allow(missing_docs)]
 #![cfg_attr(coverage_nightly, coverage(off))]
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
-use stylus_sdk::prelude::sol_interface;
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+use stylus_sdk::{abi::Bytes, prelude::sol_interface};
 
 sol_interface! {
     /// [`super::Erc1155`] token receiver interface.
@@ -67,3 +69,79 @@ sol_interface! {
         ) external returns (bytes4);
     }
 }
+
+/// Interface for any contract that wants to support safe transfers from
+/// [`super::Erc1155`] asset contracts.
+#[interface_id]
+pub trait IErc1155Receiver {
+    /// The error type associated to the trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Handles the receipt of a single ERC-1155 token type. This function is
+    /// called at the end of [`super::IErc1155::safe_transfer_from`] after the
+    /// balance has been updated.
+    ///
+    /// To accept the transfer, this must return
+    /// [`super::SINGLE_TRANSFER_FN_SELECTOR`].
+    ///
+    /// NOTE: The implementation should use
+    /// `#[selector(name = "onERC1155Received")]` to match Solidity's
+    /// camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The address which previously owned the token.
+    /// * `id` - The ID of the token being transferred.
+    /// * `value` - The amount of tokens being transferred.
+    /// * `data` - Additional data with no specified format.
+    ///
+    /// # Errors
+    ///
+    /// * If the implementation rejects the transfer.
+    #[selector(name = "onERC1155Received")]
+    fn on_erc1155_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> Result<FixedBytes<4>, Self::Error>;
+
+    /// Handles the receipt of multiple ERC-1155 token types. This function is
+    /// called at the end of [`super::IErc1155::safe_batch_transfer_from`]
+    /// after the balances have been updated.
+    ///
+    /// To accept the transfer(s), this must return
+    /// [`super::BATCH_TRANSFER_FN_SELECTOR`].
+    ///
+    /// NOTE: The implementation should use
+    /// `#[selector(name = "onERC1155BatchReceived")]` to match Solidity's
+    /// camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - The address which initiated the batch transfer.
+    /// * `from` - The address which previously owned the token.
+    /// * `ids` - An array containing ids of each token being transferred (order
+    ///   and length must match `values` array).
+    /// * `values` - An array containing amounts of each token being transferred
+    ///   (order and length must match `ids` array).
+    /// * `data` - Additional data with no specified format.
+    ///
+    /// # Errors
+    ///
+    /// * If the implementation rejects the transfer(s).
+    #[selector(name = "onERC1155BatchReceived")]
+    fn on_erc1155_batch_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> Result<FixedBytes<4>, Self::Error>;
+}
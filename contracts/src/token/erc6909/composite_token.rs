@@ -1,148 +1,670 @@
-#![no_std]
-extern crate alloc;
-
-use alloc::{vec, vec::Vec};
-use alloy_primitives::{Address, U256};
-use stylus_sdk::prelude::*; // entrypoint, storage, public, implements, HostAccess, StorageType
-
-use crate::token::erc6909::error::Error;
-use crate::token::erc6909::erc6909::Erc6909;
-use crate::token::erc6909::extensions::{Erc6909Enumerable, Erc6909MetadataUri, Erc6909Supply};
-use crate::token::erc6909::traits::{
-    IErc6909,
-    IErc6909Mintable,
-    IErc6909Burnable,
-    IErc6909Enumerable,
-    IErc6909MetadataUri,
-    IErc6909Supply,
+//! A "one-stop" ERC-6909 contract combining the core token with the
+//! enumerable, metadata, content-URI, metadata-URI and supply extensions.
+//!
+//! Balances live in a single [`Erc6909`] ledger, owned by the supply
+//! extension. Every transfer, mint and burn goes through the
+//! [`Erc6909Update::_update`] hook of [`MyToken`], which lets each extension
+//! observe the change.
+//!
+//! Minting is restricted to accounts holding [`MINTER_ROLE`], or the
+//! per-id [`super::extensions::mintable::minter_role`], granted by the admin.
+//! Accounts holding [`PAUSER_ROLE`] can pause every balance change, and the
+//! admin can cap the supply of each token type.
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, B256, U256, U8};
+use stylus_sdk::{call::MethodError, msg, prelude::*};
+
+use crate::{
+    access::control::{self, AccessControl, IAccessControl},
+    token::erc6909::{
+        erc6909::{Erc6909, Erc6909Update},
+        error::{
+            self, ERC6909FailedDecreaseAllowance, ERC6909InsufficientAllowance,
+            ERC6909InsufficientBalance, ERC6909InvalidApprover,
+            ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+            ERC6909InvalidSender, ERC6909InvalidSpender,
+        },
+        extensions::{
+            capped::{self, Erc6909Capped},
+            enumerable,
+            mintable::{Erc6909RoleMintable, MINTER_ROLE},
+            pausable::{Erc6909Pausable, PAUSER_ROLE},
+            Erc6909ContentUri, Erc6909Enumerable, Erc6909Metadata,
+            Erc6909MetadataUri, Erc6909Supply,
+        },
+        traits::{
+            IErc6909, IErc6909Allowance, IErc6909Batch, IErc6909Burnable,
+            IErc6909Capped, IErc6909ContentUri, IErc6909Enumerable,
+            IErc6909HolderEnumerable, IErc6909Metadata, IErc6909MetadataUri,
+            IErc6909Mintable, IErc6909OwnerEnumerable, IErc6909Supply,
+        },
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        pausable::{self, IPausable, Pausable},
+    },
 };
 
-/// A “one-stop” ERC-6909: core + enumerable + metadata + supply
+/// An error that occurred in the implementation of a [`MyToken`] contract.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(ERC6909FailedDecreaseAllowance),
+    /// The caller account is missing a role.
+    UnauthorizedAccount(control::AccessControlUnauthorizedAccount),
+    /// The caller of a function is not the expected one.
+    BadConfirmation(control::AccessControlBadConfirmation),
+    /// Indicates an error related to the operation that failed
+    /// because the contract had been in `Paused` state.
+    EnforcedPause(pausable::EnforcedPause),
+    /// Indicates an error related to the operation that failed
+    /// because the contract had been in `Unpaused` state.
+    ExpectedPause(pausable::ExpectedPause),
+    /// Indicates an error related to the operation that failed
+    /// because the total supply of a token type exceeded its cap.
+    ExceededCap(capped::ERC6909ExceededCap),
+    /// Indicates an error related to the operation that failed
+    /// because the supplied cap is not a valid cap of a token type.
+    InvalidCap(capped::ERC6909InvalidCap),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+impl From<error::Error> for Error {
+    fn from(value: error::Error) -> Self {
+        match value {
+            error::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            error::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            error::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            error::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            error::Error::InvalidSender(e) => Error::InvalidSender(e),
+            error::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            error::Error::InvalidArrayLength(e) => Error::InvalidArrayLength(e),
+            error::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl From<control::Error> for Error {
+    fn from(value: control::Error) -> Self {
+        match value {
+            control::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            control::Error::BadConfirmation(e) => Error::BadConfirmation(e),
+        }
+    }
+}
+
+impl From<pausable::Error> for Error {
+    fn from(value: pausable::Error) -> Self {
+        match value {
+            pausable::Error::EnforcedPause(e) => Error::EnforcedPause(e),
+            pausable::Error::ExpectedPause(e) => Error::ExpectedPause(e),
+        }
+    }
+}
+
+impl From<capped::Error> for Error {
+    fn from(value: capped::Error) -> Self {
+        match value {
+            capped::Error::ExceededCap(e) => Error::ExceededCap(e),
+            capped::Error::InvalidCap(e) => Error::InvalidCap(e),
+        }
+    }
+}
+
+/// A "one-stop" ERC-6909: core + enumerable + metadata + content URI +
+/// metadata URI + supply + capped, with role-gated minting and pausing.
 #[cfg_attr(feature = "erc6909", entrypoint)]
 #[storage]
 pub struct MyToken {
-    core:       Erc6909,
+    supply: Erc6909Supply,
     enumerable: Erc6909Enumerable,
-    metadata:   Erc6909MetadataUri,
-    supply:     Erc6909Supply,
+    metadata: Erc6909Metadata,
+    content_uri: Erc6909ContentUri,
+    metadata_uri: Erc6909MetadataUri,
+    access: AccessControl,
+    pausable: Pausable,
+    capped: Erc6909Capped,
 }
 
-// for Motsu snapshot/rollback
-unsafe impl stylus_sdk::testing::TopLevelStorage for MyToken {}
+/// NOTE: Implementation of [`TopLevelStorage`] to be able to drive [`MyToken`]
+/// from tests when it isn't compiled as the contract's entrypoint.
+#[cfg(not(feature = "erc6909"))]
+unsafe impl TopLevelStorage for MyToken {}
 
-/// Implement **all** six interfaces in one router impl:
 #[public]
 #[implements(
     IErc6909<Error = Error>,
+    IErc6909Batch<Error = Error>,
+    IErc6909Allowance<Error = Error>,
     IErc6909Mintable<Error = Error>,
     IErc6909Burnable<Error = Error>,
-    IErc6909Enumerable,
-    IErc6909MetadataUri,
-    IErc6909Supply
+    IErc6909Enumerable<Error = enumerable::Error>,
+    IErc6909OwnerEnumerable<Error = enumerable::Error>,
+    IErc6909HolderEnumerable<Error = enumerable::Error>,
+    IErc6909Metadata,
+    IErc6909ContentUri,
+    IErc6909MetadataUri<Error = Error>,
+    IErc6909Supply,
+    IErc6909Capped,
+    IAccessControl<Error = control::Error>,
+    IPausable,
+    IErc165
 )]
 impl MyToken {
-    // — Core IErc6909 —
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `name` - Default token name.
+    /// * `symbol` - Default token symbol.
+    /// * `contract_uri` - URI of the contract-level metadata.
+    /// * `uri` - URI of token types without a URI of their own, possibly
+    ///   containing `{id}`.
+    /// * `admin` - Account granted [`AccessControl::DEFAULT_ADMIN_ROLE`], which
+    ///   allows setting token URIs.
+    #[constructor]
+    pub fn constructor(
+        &mut self,
+        name: String,
+        symbol: String,
+        contract_uri: String,
+        uri: String,
+        admin: Address,
+    ) {
+        self.metadata.constructor(name, symbol);
+        self.content_uri.constructor(contract_uri, uri);
+        self.access
+            ._grant_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), admin);
+    }
+
+    /// Pauses all token transfers, mints and burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If the caller doesn't hold
+    ///   [`PAUSER_ROLE`].
+    /// * [`Error::EnforcedPause`] - If the contract is already paused.
+    ///
+    /// # Events
+    ///
+    /// * [`pausable::Paused`].
+    pub fn pause(&mut self) -> Result<(), Error> {
+        self._pause(msg::sender())
+    }
+
+    /// Unpauses all token transfers, mints and burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If the caller doesn't hold
+    ///   [`PAUSER_ROLE`].
+    /// * [`Error::ExpectedPause`] - If the contract is not paused.
+    ///
+    /// # Events
+    ///
+    /// * [`pausable::Unpaused`].
+    pub fn unpause(&mut self) -> Result<(), Error> {
+        self._unpause(msg::sender())
+    }
+
+    /// Sets `cap` as the cap on the total supply of token type `id`. A cap
+    /// can only be lowered once set.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `cap` - New cap of `id`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If the caller doesn't hold
+    ///   [`AccessControl::DEFAULT_ADMIN_ROLE`].
+    /// * [`Error::InvalidCap`] - If `cap` is zero, above the current cap of
+    ///   `id`, or below the current total supply of `id`.
+    pub fn set_cap(&mut self, id: U256, cap: U256) -> Result<(), Error> {
+        self.access._check_role(
+            AccessControl::DEFAULT_ADMIN_ROLE.into(),
+            msg::sender(),
+        )?;
+        Ok(self.capped._set_cap(id, cap, &self.supply)?)
+    }
+}
+
+#[public]
+impl IErc6909 for MyToken {
+    type Error = Error;
+
     fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.core.balance_of(owner, id)
+        self.supply.balance_of(owner, id)
     }
+
     fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.core.allowance(owner, spender, id)
+        self.supply.allowance(owner, spender, id)
     }
-    fn is_operator(&self, owner: Address, operator: Address) -> bool {
-        self.core.is_operator(owner, operator)
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.supply.is_operator(owner, spender)
     }
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self._transfer(msg::sender(), receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self._transfer_from(msg::sender(), sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
     fn approve(
         &mut self,
-        caller:  Address,
         spender: Address,
-        id:      U256,
-        amount:  U256,
-    ) -> Result<bool, Error> {
-        self.core.approve(caller, spender, id, amount)
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.supply.approve(spender, id, amount)?)
     }
+
     fn set_operator(
         &mut self,
-        caller:   Address,
-        operator: Address,
+        spender: Address,
         approved: bool,
-    ) -> Result<bool, Error> {
-        self.core.set_operator(caller, operator, approved)
+    ) -> Result<bool, Self::Error> {
+        Ok(self.supply.set_operator(spender, approved)?)
     }
-    fn transfer(
+}
+
+#[public]
+impl IErc6909Batch for MyToken {
+    type Error = Error;
+
+    fn balance_of_batch(
+        &self,
+        owners: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Self::Error> {
+        Ok(self.supply.balance_of_batch(owners, ids)?)
+    }
+
+    fn batch_transfer_from(
         &mut self,
-        caller: Address,
-        to:     Address,
-        id:     U256,
-        amount: U256,
-    ) -> Result<bool, Error> {
-        self.core.transfer(caller, to, id, amount)
+        sender: Address,
+        receiver: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, Self::Error> {
+        self._batch_transfer_from(
+            msg::sender(),
+            sender,
+            receiver,
+            ids,
+            amounts,
+        )?;
+        Ok(true)
     }
-    fn transfer_from(
+}
+
+#[public]
+impl IErc6909Allowance for MyToken {
+    type Error = Error;
+
+    fn increase_allowance(
         &mut self,
-        caller: Address,
-        from:   Address,
-        to:     Address,
-        id:     U256,
-        amount: U256,
-    ) -> Result<bool, Error> {
-        self.core.transfer_from(caller, from, to, id, amount)
+        spender: Address,
+        id: U256,
+        added_value: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.supply.increase_allowance(spender, id, added_value)?)
     }
 
-    // — Mintable + Enumerable —
+    fn decrease_allowance(
+        &mut self,
+        spender: Address,
+        id: U256,
+        subtracted_value: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.supply.decrease_allowance(spender, id, subtracted_value)?)
+    }
+}
+
+#[public]
+impl IErc6909Mintable for MyToken {
+    type Error = Error;
+
     fn mint(
         &mut self,
-        caller: Address,
-        to:     Address,
-        id:     U256,
+        to: Address,
+        id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        self.core.mint(caller, to, id, amount)?;
-        self.supply.mint(caller, to, id, amount)?;
-        self.enumerable._record_id(id);
-        Ok(())
+    ) -> Result<(), Self::Error> {
+        self._authorized_mint(msg::sender(), to, id, amount)
     }
+}
+
+#[public]
+impl IErc6909Burnable for MyToken {
+    type Error = Error;
 
-    // — Burnable + Supply —
     fn burn(
         &mut self,
-        caller: Address,
-        from:   Address,
-        id:     U256,
+        from: Address,
+        id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        self.core.burn(caller, from, id, amount)?;
-        self.supply.burn(caller, from, id, amount)?;
-        Ok(())
+    ) -> Result<(), Self::Error> {
+        self._burn_from(msg::sender(), from, id, amount)
     }
+}
+
+#[public]
+impl IErc6909Enumerable for MyToken {
+    type Error = enumerable::Error;
 
-    // — Enumerable-only —
     fn total_ids(&self) -> U256 {
         self.enumerable.total_ids()
     }
-    fn id_by_index(&self, index: U256) -> U256 {
+
+    fn id_by_index(&self, index: U256) -> Result<U256, Self::Error> {
         self.enumerable.id_by_index(index)
     }
 
-    // — Metadata-only —
-    fn token_uri(&self, id: U256) -> Vec<u8> {
-        self.metadata.token_uri(id)
+    fn ids_range(&self, offset: U256, limit: U256) -> Vec<U256> {
+        self.enumerable.ids_range(offset, limit)
+    }
+}
+
+#[public]
+impl IErc6909OwnerEnumerable for MyToken {
+    type Error = enumerable::Error;
+
+    fn ids_of_owner(&self, owner: Address) -> Vec<U256> {
+        self.enumerable.ids_of_owner(owner)
+    }
+
+    fn id_of_owner_by_index(
+        &self,
+        owner: Address,
+        index: U256,
+    ) -> Result<U256, Self::Error> {
+        self.enumerable.id_of_owner_by_index(owner, index)
+    }
+
+    fn owner_count(&self, owner: Address) -> U256 {
+        self.enumerable.owner_count(owner)
+    }
+}
+
+#[public]
+impl IErc6909HolderEnumerable for MyToken {
+    type Error = enumerable::Error;
+
+    fn holders_of(&self, id: U256) -> Vec<Address> {
+        self.enumerable.holders_of(id)
+    }
+
+    fn holder_by_index(
+        &self,
+        id: U256,
+        index: U256,
+    ) -> Result<Address, Self::Error> {
+        self.enumerable.holder_by_index(id, index)
+    }
+
+    fn holder_count(&self, id: U256) -> U256 {
+        self.enumerable.holder_count(id)
+    }
+
+    fn holders_range(
+        &self,
+        id: U256,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<Address> {
+        self.enumerable.holders_range(id, offset, limit)
+    }
+}
+
+#[public]
+impl IErc6909Metadata for MyToken {
+    fn name(&self, id: U256) -> String {
+        self.metadata.name(id)
+    }
+
+    fn symbol(&self, id: U256) -> String {
+        self.metadata.symbol(id)
+    }
+
+    fn decimals(&self, id: U256) -> U8 {
+        self.metadata.decimals(id)
+    }
+}
+
+#[public]
+impl IErc6909ContentUri for MyToken {
+    #[selector(name = "contractURI")]
+    fn contract_uri(&self) -> String {
+        self.content_uri.contract_uri()
+    }
+
+    #[selector(name = "tokenURI")]
+    fn token_uri(&self, id: U256) -> String {
+        self.metadata_uri.token_uri(id, &self.content_uri)
     }
+}
+
+#[public]
+impl IErc6909MetadataUri for MyToken {
+    type Error = Error;
+
+    /// Token URIs are managed by holders of
+    /// [`AccessControl::DEFAULT_ADMIN_ROLE`], rather than by the admin of
+    /// [`Erc6909MetadataUri`].
+    #[selector(name = "setTokenURI")]
     fn set_token_uri(
         &mut self,
-        caller: Address,
-        id:     U256,
-        uri:    Vec<u8>,
-    ) -> Result<bool, Error> {
-        self.metadata.set_token_uri(caller, id, uri)
+        id: U256,
+        uri: String,
+    ) -> Result<(), Self::Error> {
+        self.access._check_role(
+            AccessControl::DEFAULT_ADMIN_ROLE.into(),
+            msg::sender(),
+        )?;
+        self.metadata_uri._set_token_uri(id, uri);
+        Ok(())
     }
+}
 
-    // — Supply-only —
+#[public]
+impl IErc6909Supply for MyToken {
     fn total_supply(&self, id: U256) -> U256 {
         self.supply.total_supply(id)
     }
+
+    fn total_supply_all(&self) -> U256 {
+        self.supply.total_supply_all()
+    }
+
+    fn holders_count(&self, id: U256) -> U256 {
+        self.enumerable.holder_count(id)
+    }
+}
+
+#[public]
+impl IAccessControl for MyToken {
+    type Error = control::Error;
+
+    fn has_role(&self, role: B256, account: Address) -> bool {
+        self.access.has_role(role, account)
+    }
+
+    fn only_role(&self, role: B256) -> Result<(), Self::Error> {
+        self.access.only_role(role)
+    }
+
+    fn get_role_admin(&self, role: B256) -> B256 {
+        self.access.get_role_admin(role)
+    }
+
+    fn grant_role(
+        &mut self,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        self.access.grant_role(role, account)
+    }
+
+    fn revoke_role(
+        &mut self,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        self.access.revoke_role(role, account)
+    }
+
+    fn renounce_role(
+        &mut self,
+        role: B256,
+        confirmation: Address,
+    ) -> Result<(), Self::Error> {
+        self.access.renounce_role(role, confirmation)
+    }
+}
+
+#[public]
+impl IErc6909Capped for MyToken {
+    fn cap(&self, id: U256) -> U256 {
+        self.capped.cap(id)
+    }
 }
 
+#[public]
+impl IPausable for MyToken {
+    fn paused(&self) -> bool {
+        self.pausable.paused()
+    }
+}
 
+#[public]
+impl IErc165 for MyToken {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909>::interface_id() == interface_id
+            || <Self as IErc6909Batch>::interface_id() == interface_id
+            || <Self as IErc6909Allowance>::interface_id() == interface_id
+            || <Self as IErc6909Mintable>::interface_id() == interface_id
+            || <Self as IErc6909Burnable>::interface_id() == interface_id
+            || <Self as IErc6909Enumerable>::interface_id() == interface_id
+            || <Self as IErc6909OwnerEnumerable>::interface_id() == interface_id
+            || <Self as IErc6909HolderEnumerable>::interface_id()
+                == interface_id
+            || <Self as IErc6909Metadata>::interface_id() == interface_id
+            || <Self as IErc6909ContentUri>::interface_id() == interface_id
+            || <Self as IErc6909MetadataUri>::interface_id() == interface_id
+            || <Self as IErc6909Supply>::interface_id() == interface_id
+            || <Self as IErc6909Capped>::interface_id() == interface_id
+            || <Self as IAccessControl>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909Update for MyToken {
+    type Error = Error;
+
+    fn erc6909(&self) -> &Erc6909 {
+        &self.supply.erc6909
+    }
+
+    fn erc6909_mut(&mut self) -> &mut Erc6909 {
+        &mut self.supply.erc6909
+    }
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._check_not_paused()?;
+        // Holders are counted by the enumerable extension only.
+        self.supply._update_supply(from, to, id, amount)?;
+        self.capped._update(from, to, id, amount, &self.supply)?;
+        self.enumerable._update(from, to, id, amount, &self.supply);
+        Ok(())
+    }
+}
+
+impl Erc6909RoleMintable for MyToken {
+    fn access_control(&self) -> &AccessControl {
+        &self.access
+    }
+}
+
+impl Erc6909Pausable for MyToken {
+    fn pausable(&self) -> &Pausable {
+        &self.pausable
+    }
+
+    fn pausable_mut(&mut self) -> &mut Pausable {
+        &mut self.pausable
+    }
+
+    fn _check_pauser(&self, account: Address) -> Result<(), Error> {
+        Ok(self.access._check_role(PAUSER_ROLE.into(), account)?)
+    }
+}
 
 // ——————————————————————————————————————————————————————————————————————————
 // motsu-driven Unit tests
@@ -150,137 +672,577 @@ impl MyToken {
 // ——————————————————————————————————————————————————————————————————————————
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::{Address, FixedBytes, U256, U8};
+    use motsu::prelude::Contract;
+
     use super::*;
-    use alloy_primitives::{Address, U256};
-    use stylus_sdk::testing::TestVM;
-    use motsu::prelude::*;
+    use crate::{
+        access::control::AccessControlUnauthorizedAccount,
+        token::erc6909::extensions::mintable::minter_role,
+    };
 
-    fn fresh() -> Contract<MyToken> {
-        let vm = TestVM::default();
-        Contract::<MyToken>::new(&vm)
+    fn grant_minter(contract: &Contract<MyToken>, account: Address) {
+        contract.init(account, |contract| {
+            contract.access._grant_role(MINTER_ROLE.into(), account);
+        });
+    }
+
+    #[motsu::test]
+    fn holders_count_reads_holder_enumeration(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let id = U256::from(7u64);
+
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10))
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .mint(bob, id, U256::from(10))
+            .expect("should mint");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::from(2));
+
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::from(10))
+            .expect("should transfer all of Alice's tokens");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ONE);
+        assert_eq!(
+            contract.sender(alice).holders_count(id),
+            contract.sender(alice).holder_count(id)
+        );
+        // The supply extension's own counter is not kept.
+        assert_eq!(contract.sender(alice).supply.holders_count(id), U256::ZERO);
     }
 
     #[motsu::test]
     fn full_mint_transfer_burn_flow(
-        mut c: Contract<MyToken>,
-        samuel: Account,
-        mother:   Account,
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
     ) {
+        grant_minter(&contract, alice);
         let id = U256::from(7u64);
 
         // mint
-        c.mint(samuel.address(), samuel.address(), id, U256::from(100))
-            .motsu_unwrap();
-        assert_eq!(c.balance_of(samuel.address(), id), U256::from(100));
-        assert_eq!(c.total_supply(id), U256::from(100));
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(100))
+            .expect("should mint tokens for Alice");
+        assert_eq!(
+            contract.sender(alice).balance_of(alice, id),
+            U256::from(100)
+        );
+        assert_eq!(contract.sender(alice).total_supply(id), U256::from(100));
 
         // transfer + enumerate
-        c.approve(samuel.address(), mother.address(), id, U256::from(50))
-            .motsu_unwrap();
-        c.transfer_from(
-            samuel.address(),
-            samuel.address(),
-            mother.address(),
-            id,
-            U256::from(30),
-        )
-        .motsu_unwrap();
-        assert_eq!(c.balance_of(mother.address(), id), U256::from(30));
-        assert_eq!(c.total_ids(), U256::from(1));
-        assert_eq!(c.id_by_index(U256::ZERO), id);
+        contract
+            .sender(alice)
+            .approve(bob, id, U256::from(50))
+            .expect("should approve Bob");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, id, U256::from(30))
+            .expect("should transfer Alice's tokens to Bob");
+        assert_eq!(contract.sender(alice).balance_of(bob, id), U256::from(30));
+        assert_eq!(contract.sender(alice).total_ids(), U256::from(1));
+        assert_eq!(
+            contract
+                .sender(alice)
+                .id_by_index(U256::ZERO)
+                .expect("should be in bounds"),
+            id
+        );
 
         // burn
-        c.burn(samuel.address(), samuel.address(), id, U256::from(10))
-            .motsu_unwrap();
-        assert_eq!(c.total_supply(id), U256::from(90));
+        contract
+            .sender(alice)
+            .burn(alice, id, U256::from(10))
+            .expect("should burn Alice's tokens");
+        assert_eq!(contract.sender(alice).total_supply(id), U256::from(90));
+    }
+
+    #[motsu::test]
+    fn balances_and_supply_share_one_ledger(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let id = U256::from(7u64);
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(100))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::from(40))
+            .expect("should transfer Alice's tokens to Bob");
+        contract
+            .sender(bob)
+            .burn(bob, id, U256::from(15))
+            .expect("should burn Bob's tokens");
+
+        let alice_balance = contract.sender(alice).balance_of(alice, id);
+        let bob_balance = contract.sender(alice).balance_of(bob, id);
+        assert_eq!(alice_balance, U256::from(60));
+        assert_eq!(bob_balance, U256::from(25));
+        assert_eq!(
+            contract.sender(alice).total_supply(id),
+            alice_balance + bob_balance
+        );
     }
 
     #[motsu::test]
-    fn metadata_round_trip(mut c: Contract<MyToken>, samuel: Account) {
+    fn metadata_round_trip(contract: Contract<MyToken>, alice: Address) {
         let id = U256::from(42);
-        let uri = b"ipfs://foo".to_vec();
-        c.set_token_uri(samuel.address(), id, uri.clone())
-            .motsu_unwrap();
-        assert_eq!(c.token_uri(id), uri);
+        let uri = String::from("ipfs://foo");
+        contract.sender(alice).constructor(
+            String::from("Token"),
+            String::from("TKN"),
+            String::from("ipfs://contract"),
+            String::from("ipfs://base/{id}.json"),
+            alice,
+        );
+        contract
+            .sender(alice)
+            .set_token_uri(id, uri.clone())
+            .expect("should set token URI");
+        assert_eq!(contract.sender(alice).token_uri(id), uri);
+        assert_eq!(
+            contract.sender(alice).token_uri(U256::ONE),
+            "ipfs://base/{id}.json"
+        );
+        assert_eq!(contract.sender(alice).contract_uri(), "ipfs://contract");
     }
 
     #[motsu::test]
-    fn enumeration_handles_duplicates(mut c: Contract<MyToken>, samuel: Account) {
+    fn set_token_uri_follows_admin_role(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(42);
+        contract.sender(alice).constructor(
+            String::from("Token"),
+            String::from("TKN"),
+            String::from("ipfs://contract"),
+            String::from("ipfs://base/{id}.json"),
+            alice,
+        );
+        contract.init(alice, |contract| {
+            contract
+                .access
+                ._grant_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), bob);
+            contract
+                .access
+                ._revoke_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), alice);
+        });
+
+        let err = contract
+            .sender(alice)
+            .set_token_uri(id, String::from("ipfs://alice"))
+            .expect_err("should not set token URI after losing the role");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(AccessControlUnauthorizedAccount {
+                account,
+                needed_role,
+            }) if account == alice
+                && needed_role == B256::from(AccessControl::DEFAULT_ADMIN_ROLE)
+        ));
+
+        contract
+            .sender(bob)
+            .set_token_uri(id, String::from("ipfs://bob"))
+            .expect("should set token URI as the new admin");
+        assert_eq!(contract.sender(bob).token_uri(id), "ipfs://bob");
+    }
+
+    #[motsu::test]
+    fn metadata_falls_back_to_defaults(
+        contract: Contract<MyToken>,
+        alice: Address,
+    ) {
+        let id = U256::from(42);
+        contract.sender(alice).constructor(
+            String::from("Token"),
+            String::from("TKN"),
+            String::from("ipfs://contract"),
+            String::from("ipfs://base/{id}.json"),
+            alice,
+        );
+        contract.init(alice, |contract| {
+            contract.metadata._set_decimals(id, U8::from(6));
+        });
+
+        assert_eq!(contract.sender(alice).name(id), "Token");
+        assert_eq!(contract.sender(alice).symbol(id), "TKN");
+        assert_eq!(contract.sender(alice).decimals(id), U8::from(6));
+        assert_eq!(contract.sender(alice).decimals(U256::ONE), U8::from(18));
+    }
+
+    #[motsu::test]
+    fn enumeration_handles_duplicates(
+        contract: Contract<MyToken>,
+        alice: Address,
+    ) {
+        grant_minter(&contract, alice);
         let id = U256::from(99);
-        c.mint(samuel.address(), samuel.address(), id, U256::ONE)
-            .motsu_unwrap();
-        c.mint(samuel.address(), samuel.address(), id, U256::ONE)
-            .motsu_unwrap();
-        assert_eq!(c.total_ids(), U256::ONE);
+        contract.sender(alice).mint(alice, id, U256::ONE).expect("should mint");
+        contract.sender(alice).mint(alice, id, U256::ONE).expect("should mint");
+        assert_eq!(contract.sender(alice).total_ids(), U256::ONE);
+    }
+
+    #[motsu::test]
+    fn enumeration_lists_ids_of_owner(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let (id1, id2) = (U256::from(1), U256::from(2));
+        contract
+            .sender(alice)
+            .mint(alice, id1, U256::ONE)
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .mint(alice, id2, U256::ONE)
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .transfer(bob, id1, U256::ONE)
+            .expect("should transfer");
+
+        assert_eq!(contract.sender(alice).ids_of_owner(alice), vec![id2]);
+        assert_eq!(contract.sender(alice).ids_of_owner(bob), vec![id1]);
+        assert_eq!(contract.sender(alice).holders_of(id1), vec![bob]);
+        assert_eq!(contract.sender(alice).holder_count(id2), U256::ONE);
+        assert_eq!(contract.sender(alice).owner_count(alice), U256::ONE);
+        assert_eq!(contract.sender(alice).total_ids(), U256::from(2));
     }
 
     #[motsu::test]
-    fn out_of_bounds_index_returns_zero(mut c: Contract<MyToken>) {
-        assert_eq!(c.id_by_index(U256::from(5)), U256::ZERO);
+    fn out_of_bounds_index_reverts(
+        contract: Contract<MyToken>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .id_by_index(U256::from(5))
+            .expect_err("should not get an id out of bounds");
+        assert!(matches!(
+            err,
+            enumerable::Error::OutOfBoundsIndex(
+                enumerable::ERC6909OutOfBoundsIndex { index }
+            ) if index == U256::from(5)
+        ));
     }
 
     #[motsu::test]
-    fn invalid_zero_caller_reverts(mut c: Contract<MyToken>) {
-        let id   = U256::ONE;
-        let zero = Address::new([0; 20]);
-        c.set_token_uri(zero, id, b"x".to_vec()).motsu_unwrap_err();
-        c.mint(zero, zero, id, U256::ONE).motsu_unwrap_err();
-        c.burn(zero, zero, id, U256::ONE).motsu_unwrap_err();
+    fn invalid_zero_address_reverts(
+        contract: Contract<MyToken>,
+        alice: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let id = U256::ONE;
+        contract
+            .sender(alice)
+            .mint(Address::ZERO, id, U256::ONE)
+            .expect_err("should not mint to the zero address");
+        contract
+            .sender(alice)
+            .burn(Address::ZERO, id, U256::ONE)
+            .expect_err("should not burn from the zero address");
     }
 
     #[motsu::test]
-    fn test_token_transfers(token: Contract<MyToken>, samuel: Account, mother: Account) {
-        let id     = U256::from(1);
+    fn test_token_transfers(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let id = U256::from(1);
         let amount = U256::from(100);
 
-        // mint 100 to samuel
-        token
-            .sender(samuel)
-            .mint(samuel.address(), samuel.address(), id, amount)
-            .motsu_unwrap();
+        // mint 100 to alice
+        contract
+            .sender(alice)
+            .mint(alice, id, amount)
+            .expect("should mint tokens for Alice");
+
+        // alice's balance is 100, bob's is 0
+        assert_eq!(contract.sender(alice).balance_of(alice, id), amount);
+        assert_eq!(contract.sender(alice).balance_of(bob, id), U256::ZERO);
+
+        // alice approves bob for 30
+        contract
+            .sender(alice)
+            .approve(bob, id, U256::from(30))
+            .expect("should approve Bob");
 
-        // samuel’s balance is 100, mother’s is 0
+        // bob pulls 30 out of alice's balance
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, id, U256::from(30))
+            .expect("should transfer Alice's tokens to Bob");
+
+        // balances updated
         assert_eq!(
-            token.sender(samuel).balance_of(samuel.address(), id),
-            amount
+            contract.sender(alice).balance_of(alice, id),
+            amount - U256::from(30)
         );
+        assert_eq!(contract.sender(bob).balance_of(bob, id), U256::from(30));
+
+        // enumeration picked up that token-ID
+        assert_eq!(contract.sender(alice).total_ids(), U256::from(1));
         assert_eq!(
-            token.sender(samuel).balance_of(mother.address(),   id),
-            U256::ZERO
+            contract
+                .sender(alice)
+                .id_by_index(U256::ZERO)
+                .expect("should be in bounds"),
+            id
         );
 
-        // samuel approves mother for 30
-        token
-            .sender(samuel)
-            .approve(samuel.address(), mother.address(), id, U256::from(30))
-            .motsu_unwrap();
+        // if alice tries to send more than they have: revert
+        let too_much = amount * U256::from(2);
+        let err = contract
+            .sender(alice)
+            .transfer(bob, id, too_much)
+            .expect_err("should not transfer more than the balance");
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id: token_id,
+            }) if sender == alice
+                && balance == amount - U256::from(30)
+                && needed == too_much
+                && token_id == id
+        ));
+    }
 
-        // mother pulls 30 out of samuel’s balance
-        token
-            .sender(mother)
-            .transfer_from(samuel.address(), samuel.address(), mother.address(), id, U256::from(30))
-            .motsu_unwrap();
+    #[motsu::test]
+    fn caller_cannot_spend_anothers_balance(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        grant_minter(&contract, alice);
+        let id = U256::from(1);
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10))
+            .expect("should mint tokens for Alice");
 
-        // balances updated
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, id, U256::from(10))
+            .expect_err("should not transfer without allowance");
         assert_eq!(
-            token.sender(samuel).balance_of(samuel.address(), id),
-            amount - U256::from(30)
+            contract.sender(alice).balance_of(alice, id),
+            U256::from(10)
         );
-        assert_eq!(
-            token.sender(mother).balance_of(mother.address(), id),
-            U256::from(30)
+    }
+
+    #[motsu::test]
+    fn mint_requires_minter_role(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(1);
+        let err = contract
+            .sender(bob)
+            .mint(bob, id, U256::ONE)
+            .expect_err("should not mint without a minter role");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(AccessControlUnauthorizedAccount {
+                account,
+                needed_role,
+            }) if account == bob && needed_role == minter_role(id)
+        ));
+
+        contract.sender(alice).constructor(
+            String::from("Token"),
+            String::from("TKN"),
+            String::from("ipfs://contract"),
+            String::from("ipfs://base/{id}.json"),
+            alice,
         );
+        contract
+            .sender(alice)
+            .grant_role(MINTER_ROLE.into(), bob)
+            .expect("should grant Bob the minter role");
+        contract
+            .sender(bob)
+            .mint(bob, id, U256::ONE)
+            .expect("should mint as minter");
+        assert_eq!(contract.sender(bob).balance_of(bob, id), U256::ONE);
+    }
 
-        // enumeration picked up that token‐ID
-        assert_eq!(token.total_ids(), U256::from(1));
-        assert_eq!(token.id_by_index(U256::ZERO), id);
+    #[motsu::test]
+    fn per_id_minter_role_only_mints_its_id(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(1);
+        let other_id = U256::from(2);
+        contract.sender(alice).constructor(
+            String::from("Token"),
+            String::from("TKN"),
+            String::from("ipfs://contract"),
+            String::from("ipfs://base/{id}.json"),
+            alice,
+        );
+        contract
+            .sender(alice)
+            .grant_role(minter_role(id), bob)
+            .expect("should grant Bob the minter role of `id`");
 
-        // if samuel tries to send more than she has: revert
-        let too_much = amount * U256::from(2);
-        let err = token
-            .sender(samuel)
-            .transfer(mother.address(), id, too_much)
-            .motsu_unwrap_err();
-         assert!(matches!(err, erc6909::Error::InsufficientBalance(_)));
+        contract
+            .sender(bob)
+            .mint(bob, id, U256::ONE)
+            .expect("should mint `id` as its minter");
+        let err = contract
+            .sender(bob)
+            .mint(bob, other_id, U256::ONE)
+            .expect_err("should not mint another id");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(AccessControlUnauthorizedAccount {
+                account,
+                needed_role,
+            }) if account == bob && needed_role == minter_role(other_id)
+        ));
+        assert_eq!(contract.sender(bob).total_supply(other_id), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn pauser_role_pauses_balance_changes(
+        contract: Contract<MyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(1);
+        grant_minter(&contract, alice);
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(5))
+            .expect("should mint");
+
+        let err = contract
+            .sender(bob)
+            .pause()
+            .expect_err("should not pause without the pauser role");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(AccessControlUnauthorizedAccount {
+                account,
+                needed_role,
+            }) if account == bob && needed_role == B256::from(PAUSER_ROLE)
+        ));
+
+        contract.init(alice, |contract| {
+            contract.access._grant_role(PAUSER_ROLE.into(), bob);
+        });
+        contract.sender(bob).pause().expect("should pause as pauser");
+        assert!(contract.sender(alice).paused());
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, id, U256::ONE)
+            .expect_err("should not transfer while paused");
+        assert!(matches!(err, Error::EnforcedPause(_)));
+        let err = contract
+            .sender(alice)
+            .mint(alice, id, U256::ONE)
+            .expect_err("should not mint while paused");
+        assert!(matches!(err, Error::EnforcedPause(_)));
+        let err = contract
+            .sender(alice)
+            .burn(alice, id, U256::ONE)
+            .expect_err("should not burn while paused");
+        assert!(matches!(err, Error::EnforcedPause(_)));
+
+        contract.sender(bob).unpause().expect("should unpause as pauser");
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::ONE)
+            .expect("should transfer when unpaused");
+        assert_eq!(contract.sender(alice).balance_of(bob, id), U256::ONE);
+        assert_eq!(contract.sender(alice).total_supply(id), U256::from(5));
+    }
+
+    #[motsu::test]
+    fn admin_caps_supply(contract: Contract<MyToken>, alice: Address) {
+        let id = U256::from(1);
+        contract.init(alice, |contract| {
+            contract
+                .access
+                ._grant_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), alice);
+        });
+        grant_minter(&contract, alice);
+        contract
+            .sender(alice)
+            .set_cap(id, U256::from(10))
+            .expect("should set the cap as admin");
+        assert_eq!(contract.sender(alice).cap(id), U256::from(10));
+        assert_eq!(contract.sender(alice).cap(U256::from(2)), U256::MAX);
+
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10))
+            .expect("should mint up to the cap");
+        let err = contract
+            .sender(alice)
+            .mint(alice, id, U256::ONE)
+            .expect_err("should not mint above the cap");
+        assert!(matches!(
+            err,
+            Error::ExceededCap(capped::ERC6909ExceededCap {
+                increased_supply,
+                cap,
+                ..
+            }) if increased_supply == U256::from(11) && cap == U256::from(10)
+        ));
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<MyToken>, alice: Address) {
+        let erc6909_id: FixedBytes<4> = 0x0f632fb3_u32.into();
+        assert!(contract.sender(alice).supports_interface(erc6909_id));
+        for interface_id in [
+            <MyToken as IErc6909Allowance>::interface_id(),
+            <MyToken as IErc6909Mintable>::interface_id(),
+            <MyToken as IErc6909Burnable>::interface_id(),
+            <MyToken as IErc6909Enumerable>::interface_id(),
+            <MyToken as IErc6909OwnerEnumerable>::interface_id(),
+            <MyToken as IErc6909HolderEnumerable>::interface_id(),
+            <MyToken as IErc6909Metadata>::interface_id(),
+            <MyToken as IErc6909ContentUri>::interface_id(),
+            <MyToken as IErc6909MetadataUri>::interface_id(),
+            <MyToken as IErc6909Supply>::interface_id(),
+            <MyToken as IErc6909Capped>::interface_id(),
+            <MyToken as IAccessControl>::interface_id(),
+            <MyToken as IErc165>::interface_id(),
+        ] {
+            assert!(contract.sender(alice).supports_interface(interface_id));
+        }
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
     }
 }
@@ -1,339 +1,1722 @@
-// contracts/src/erc6909/erc6909.rs 
-// FINAL VERSION WORKED FINE
-// All test passed
-
-#![no_std]
-extern crate alloc;
-
-// bring vec![] macro and Vec<T> into scope
+//! Implementation of the ERC-6909 token standard.
+//!
+//! Every state-changing entry point acts on behalf of `msg::sender()`. Use
+//! the internal `_`-prefixed functions to act on behalf of another account.
 use alloc::{vec, vec::Vec};
 
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    prelude::{storage, HostAccess, StorageType},
-    storage::{StorageBool, StorageMap, StorageUint},
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageMap, StorageU256},
 };
 
 use super::{
-    error::Error,
-    traits::{IErc6909, IErc6909Burnable, IErc6909Mintable},
+    error::{
+        ERC6909FailedDecreaseAllowance, ERC6909InsufficientAllowance,
+        ERC6909InsufficientBalance, ERC6909InvalidApprover,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender, Error,
+    },
+    traits::{
+        IErc6909, IErc6909Allowance, IErc6909Batch, IErc6909Burnable,
+        IErc6909Mintable,
+    },
+};
+use crate::utils::{
+    introspection::erc165::IErc165, math::storage::AddAssignChecked,
 };
 
-// use stylus_sdk::testing::TestVM;
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
 
+    sol! {
+        /// Emitted when `amount` tokens of type `id` are moved from `sender`
+        /// to `receiver` by `caller`.
+        ///
+        /// Mints have `sender` set to the zero address, burns have `receiver`
+        /// set to the zero address.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Transfer(
+            address caller,
+            address indexed sender,
+            address indexed receiver,
+            uint256 indexed id,
+            uint256 amount
+        );
 
-/// Core ERC-6909 (no supply tracking), single-ID multi-token standard.
-///
-/// All storage fields get a `::new(...)` constructor generated by the
-/// `#[storage]` macro (requires `StorageType` in scope).
+        /// Emitted when `owner` sets `approved` as the operator status of
+        /// `spender`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OperatorSet(
+            address indexed owner,
+            address indexed spender,
+            bool approved
+        );
+
+        /// Emitted when the allowance of `spender` over `owner`'s tokens of
+        /// type `id` is set to `amount` by a call to `approve`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Approval(
+            address indexed owner,
+            address indexed spender,
+            uint256 indexed id,
+            uint256 amount
+        );
+    }
+}
+
+pub use sol::*;
+
+/// State of an [`Erc6909`] token.
 #[storage]
 pub struct Erc6909 {
-    /// owner ⇒ (id ⇒ balance)
-    balances: StorageMap<Address, StorageMap<U256, StorageUint<256, 4>>>,
+    /// Maps owners to a mapping of token id balances.
+    pub(crate) balances: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Maps owners to a mapping of each spender's per-id allowance.
+    pub(crate) allowances:
+        StorageMap<Address, StorageMap<Address, StorageMap<U256, StorageU256>>>,
+    /// Maps owners to a mapping of operator approvals.
+    pub(crate) operator_approvals:
+        StorageMap<Address, StorageMap<Address, StorageBool>>,
+}
 
-    /// owner ⇒ (spender ⇒ (id ⇒ allowance))
-    allowances: StorageMap<
-        Address,
-        StorageMap<Address, StorageMap<U256, StorageUint<256, 4>>>,
-    >,
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909 {}
 
-    /// owner ⇒ (operator ⇒ approved?)
-    operator_approvals: StorageMap<Address, StorageMap<Address, StorageBool>>,
-}
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Batch<Error = Error>,
+    IErc6909Allowance<Error = Error>,
+    IErc165
+)]
+impl Erc6909 {}
 
-impl Erc6909 {
-    #[inline(always)]
-    fn _update(
+#[public]
+impl IErc6909 for Erc6909 {
+    type Error = Error;
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.balances.get(owner).get(id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.allowances.get(owner).get(spender).get(id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.operator_approvals.get(owner).get(spender)
+    }
+
+    fn transfer(
         &mut self,
-        from: Address,
-        to: Address,
+        receiver: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        // debit from
-        if from != Address::new([0u8; 20]) {
-            let mut by_owner = self.balances.setter(from);
-            let mut slot = by_owner.setter(id);
-            let prev = slot.get();
-            if prev < amount {
-                return Err(Error::InsufficientBalance);
-            }
-            slot.set(prev - amount);
-        }
-        // credit to
-        if to != Address::new([0u8; 20]) {
-            let mut by_owner = self.balances.setter(to);
-            let mut slot = by_owner.setter(id);
-            let prev = slot.get();
-            let next = prev.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
-            slot.set(next);
-        }
-        Ok(())
+    ) -> Result<bool, Self::Error> {
+        self._transfer(msg::sender(), receiver, id, amount)?;
+        Ok(true)
     }
 
-    #[inline(always)]
-    pub fn _mint(
+    fn transfer_from(
         &mut self,
-        to: Address,
+        sender: Address,
+        receiver: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        if to == Address::new([0u8; 20]) {
-            return Err(Error::InvalidReceiver);
-        }
-        self._update(Address::new([0u8; 20]), to, id, amount)
+    ) -> Result<bool, Self::Error> {
+        self._transfer_from(msg::sender(), sender, receiver, id, amount)?;
+        Ok(true)
     }
 
-    #[inline(always)]
-    pub fn _burn(
+    fn approve(
         &mut self,
-        from: Address,
+        spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        if from == Address::new([0u8; 20]) {
-            return Err(Error::InvalidSender);
+    ) -> Result<bool, Self::Error> {
+        self._approve(msg::sender(), spender, id, amount)?;
+        Ok(true)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self._set_operator(msg::sender(), spender, approved)?;
+        Ok(true)
+    }
+}
+
+#[public]
+impl IErc6909Batch for Erc6909 {
+    type Error = Error;
+
+    fn balance_of_batch(
+        &self,
+        owners: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Self::Error> {
+        Self::require_equal_arrays_length(&ids, &owners)?;
+
+        let balances: Vec<U256> = owners
+            .iter()
+            .zip(ids.iter())
+            .map(|(owner, id)| self.balance_of(*owner, *id))
+            .collect();
+
+        Ok(balances)
+    }
+
+    fn batch_transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, Self::Error> {
+        self._batch_transfer_from(
+            msg::sender(),
+            sender,
+            receiver,
+            ids,
+            amounts,
+        )?;
+        Ok(true)
+    }
+}
+
+#[public]
+impl IErc6909Allowance for Erc6909 {
+    type Error = Error;
+
+    fn increase_allowance(
+        &mut self,
+        spender: Address,
+        id: U256,
+        added_value: U256,
+    ) -> Result<bool, Self::Error> {
+        let owner = msg::sender();
+        let current_allowance = self.allowance(owner, spender, id);
+        let new_allowance = current_allowance
+            .checked_add(added_value)
+            .expect("should not exceed `U256::MAX` for `allowance`");
+        self._approve(owner, spender, id, new_allowance)?;
+        Ok(true)
+    }
+
+    fn decrease_allowance(
+        &mut self,
+        spender: Address,
+        id: U256,
+        subtracted_value: U256,
+    ) -> Result<bool, Self::Error> {
+        let owner = msg::sender();
+        let current_allowance = self.allowance(owner, spender, id);
+        if current_allowance < subtracted_value {
+            return Err(Error::FailedDecreaseAllowance(
+                ERC6909FailedDecreaseAllowance {
+                    spender,
+                    id,
+                    current_allowance,
+                    requested_decrease: subtracted_value,
+                },
+            ));
         }
-        self._update(from, Address::new([0u8; 20]), id, amount)
+        self._approve(
+            owner,
+            spender,
+            id,
+            current_allowance - subtracted_value,
+        )?;
+        Ok(true)
     }
 }
 
-impl IErc6909 for Erc6909 {
-    fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.balances.get(owner).get(id)
+#[public]
+impl IErc165 for Erc6909 {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909>::interface_id() == interface_id
+            || <Self as IErc6909Batch>::interface_id() == interface_id
+            || <Self as IErc6909Allowance>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
     }
+}
 
-    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.allowances.get(owner).get(spender).get(id)
+impl IErc6909Mintable for Erc6909 {
+    type Error = Error;
+
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
     }
+}
 
-    fn is_operator(&self, owner: Address, operator: Address) -> bool {
-        self.operator_approvals.get(owner).get(operator)
+impl IErc6909Burnable for Erc6909 {
+    type Error = Error;
+
+    fn burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn_from(msg::sender(), from, id, amount)
     }
+}
 
-    fn approve(
+impl Erc6909 {
+    /// Sets an `amount` of tokens of type `id` as the allowance of `spender`
+    /// over the `owner`'s tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApprover`] - If `owner` is [`Address::ZERO`].
+    /// * [`Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`].
+    pub fn _approve(
         &mut self,
-        caller: Address,
+        owner: Address,
         spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        // reject any zero-address as approver or spender
-        if caller == Address::new([0u8; 20]) {
-            return Err(Error::InvalidApprover);
+    ) -> Result<(), Error> {
+        if owner.is_zero() {
+            return Err(Error::InvalidApprover(ERC6909InvalidApprover {
+                approver: owner,
+            }));
         }
-        if spender == Address::new([0u8; 20]) {
-            return Err(Error::InvalidApprover);
+        if spender.is_zero() {
+            return Err(Error::InvalidSpender(ERC6909InvalidSpender {
+                spender,
+            }));
         }
-        let mut by_owner = self.allowances.setter(caller);
-        let mut by_spender = by_owner.setter(spender);
-        let mut slot = by_spender.setter(id);
-        slot.set(amount);
-        Ok(true)
+        self.allowances.setter(owner).setter(spender).insert(id, amount);
+        evm::log(Approval { owner, spender, id, amount });
+        Ok(())
     }
 
-    fn set_operator(
+    /// Grants or revokes operator permission of `spender` over all of
+    /// `owner`'s tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account to add to the set of authorized operators.
+    /// * `approved` - Flag that determines whether or not permission will be
+    ///   granted to `spender`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApprover`] - If `owner` is [`Address::ZERO`].
+    /// * [`Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`OperatorSet`].
+    pub fn _set_operator(
         &mut self,
-        caller: Address,
-        operator: Address,
+        owner: Address,
+        spender: Address,
         approved: bool,
-    ) -> Result<bool, Error> {
-        if caller == Address::new([0u8; 20]) {
-            return Err(Error::InvalidApprover);
+    ) -> Result<(), Error> {
+        if owner.is_zero() {
+            return Err(Error::InvalidApprover(ERC6909InvalidApprover {
+                approver: owner,
+            }));
         }
-        if operator == Address::new([0u8; 20]) {
-            return Err(Error::InvalidSpender);
+        if spender.is_zero() {
+            return Err(Error::InvalidSpender(ERC6909InvalidSpender {
+                spender,
+            }));
         }
-        let mut by_owner = self.operator_approvals.setter(caller);
-        let mut slot = by_owner.setter(operator);
-        slot.set(approved);
-        Ok(true)
+        self.operator_approvals.setter(owner).insert(spender, approved);
+        evm::log(OperatorSet { owner, spender, approved });
+        Ok(())
     }
 
-    fn transfer(
+    /// Decreases the allowance of `spender` over `owner`'s tokens of type
+    /// `id` by `amount`.
+    ///
+    /// Does not update the allowance if it is [`U256::MAX`], which stands for
+    /// an infinite allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that spends the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens spent.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientAllowance`] - If not enough allowance is
+    ///   available.
+    pub fn _spend_allowance(
         &mut self,
-        caller: Address,
-        to: Address,
+        owner: Address,
+        spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        self.transfer_from(caller, caller, to, id, amount)
+    ) -> Result<(), Error> {
+        let current_allowance = self.allowance(owner, spender, id);
+        if current_allowance == U256::MAX {
+            return Ok(());
+        }
+        if current_allowance < amount {
+            return Err(Error::InsufficientAllowance(
+                ERC6909InsufficientAllowance {
+                    spender,
+                    allowance: current_allowance,
+                    needed: amount,
+                    id,
+                },
+            ));
+        }
+        self.allowances
+            .setter(owner)
+            .setter(spender)
+            .insert(id, current_allowance - amount);
+        Ok(())
     }
 
-    fn transfer_from(
+    /// Checks that `spender` may move an `amount` of `owner`'s tokens of type
+    /// `id`, spending its allowance unless it is `owner` itself or one of
+    /// `owner`'s operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that moves the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `owner` nor
+    ///   an operator, and not enough allowance is available.
+    pub fn _authorize_transfer(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if spender != owner && !self.is_operator(owner, spender) {
+            self._spend_allowance(owner, spender, id, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Checks if `ids` array has same length as `values` array.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - array of `ids`.
+    /// * `values` - array of `values`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `values`.
+    pub fn require_equal_arrays_length<T, U>(
+        ids: &[T],
+        values: &[U],
+    ) -> Result<(), Error> {
+        if ids.len() != values.len() {
+            return Err(Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+                ids_length: U256::from(ids.len()),
+                values_length: U256::from(values.len()),
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// Hook through which every balance change of an [`Erc6909`] ledger goes.
+///
+/// Extensions layer over the ledger by implementing [`Self::_update`] in
+/// terms of the `_update` of the layer below, and then doing their own
+/// bookkeeping, the same way `Erc721Consecutive::_update` wraps
+/// [`crate::token::erc721::Erc721`]. Transfers, mints and burns are provided
+/// on top of [`Self::_update`], so a contract only has to implement the hook
+/// to have all of them go through every layer.
+pub trait Erc6909Update {
+    /// The error type returned by the hook. Layers may widen it, e.g. to
+    /// report a paused contract, but it must at least carry [`Error`].
+    type Error: From<Error>;
+
+    /// Returns the [`Erc6909`] ledger the hook ultimately updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn erc6909(&self) -> &Erc6909;
+
+    /// Returns the [`Erc6909`] ledger the hook ultimately updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    fn erc6909_mut(&mut self) -> &mut Erc6909;
+
+    /// Transfers an `amount` of tokens of type `id` from `from` to `to`.
+    /// Will mint (or burn) if `from` (or `to`) is the [`Address::ZERO`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    ///
+    /// # Panics
+    ///
+    /// * If the balance of `to` would exceed [`U256::MAX`], may happen during a
+    ///   mint.
+    fn _update(
         &mut self,
-        caller: Address,
         from: Address,
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        if from == Address::new([0u8; 20]) {
-            return Err(Error::InvalidSender);
-        }
-        if to == Address::new([0u8; 20]) {
-            return Err(Error::InvalidReceiver);
+    ) -> Result<(), Self::Error>;
+
+    /// Moves an `amount` of tokens of type `id` from `from` to `to`, without
+    /// checking the caller's rights.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            })
+            .into());
         }
-        if caller != from && !self.is_operator(from, caller) {
-            let mut by_owner = self.allowances.setter(from);
-            let mut by_caller = by_owner.setter(caller);
-            let mut slot = by_caller.setter(id);
-            let allowance = slot.get();
-            if allowance < amount {
-                return Err(Error::InsufficientAllowance);
-            }
-            slot.set(allowance - amount);
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            })
+            .into());
         }
-        self._update(from, to, id, amount)?;
-        Ok(true)
+        self._update(from, to, id, amount)
     }
-}
 
-impl IErc6909Mintable for Erc6909 {
-    #[inline]
-    fn mint(
+    /// Moves an `amount` of tokens of type `id` from `from` to `to` on behalf
+    /// of `spender`, spending `spender`'s allowance unless it is `from`
+    /// itself or one of `from`'s operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that moves the tokens.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `from` nor
+    ///   an operator, and not enough allowance is available.
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    fn _transfer_from(
+        &mut self,
+        spender: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909_mut()._authorize_transfer(from, spender, id, amount)?;
+        self._transfer(from, to, id, amount)
+    }
+
+    /// Creates an `amount` of tokens of type `id` and assigns them to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    ///
+    /// # Panics
+    ///
+    /// * If the balance of `to` would exceed [`U256::MAX`].
+    fn _mint(
         &mut self,
-        caller: Address,
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
-        // guard against zero caller or recipient
-        if caller == Address::new([0u8; 20]) {
-            return Err(Error::InvalidApprover);
+    ) -> Result<(), Self::Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            })
+            .into());
         }
-        if to == Address::new([0u8; 20]) {
-            return Err(Error::InvalidApprover);
+        self._update(Address::ZERO, to, id, amount)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to burn tokens from.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            })
+            .into());
         }
-        // proceed with internal mint
-        self._mint(to, id, amount)
+        self._update(from, Address::ZERO, id, amount)
     }
-}
 
-impl IErc6909Burnable for Erc6909 {
-    #[inline]
-    fn burn(
+    /// Destroys an `amount` of tokens of type `id` from `from` on behalf of
+    /// `spender`, spending `spender`'s allowance unless it is `from` itself
+    /// or one of `from`'s operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that burns the tokens.
+    /// * `from` - Account to burn tokens from.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `from` nor
+    ///   an operator, and not enough allowance is available.
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    fn _burn_from(
         &mut self,
-        _caller: Address,
+        spender: Address,
         from: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error> {
+    ) -> Result<(), Self::Error> {
+        self.erc6909_mut()._authorize_transfer(from, spender, id, amount)?;
         self._burn(from, id, amount)
     }
+
+    /// Batched version of [`Self::_transfer_from`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that moves the tokens.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - All token identifiers.
+    /// * `amounts` - All amounts to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `from` nor
+    ///   an operator, and not enough allowance is available for any of the
+    ///   `ids`.
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least the amount of any of the `ids`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`] - For each pair of `ids` and `amounts`.
+    fn _batch_transfer_from(
+        &mut self,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Erc6909::require_equal_arrays_length(&ids, &amounts)?;
+
+        for (&id, &amount) in ids.iter().zip(amounts.iter()) {
+            self._transfer_from(spender, from, to, id, amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - All token identifiers.
+    /// * `amounts` - All amounts to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`] - For each pair of `ids` and `amounts`.
+    ///
+    /// # Panics
+    ///
+    /// * If the balance of `to` would exceed [`U256::MAX`] for any of the
+    ///   `ids`.
+    fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Erc6909::require_equal_arrays_length(&ids, &amounts)?;
+
+        for (&id, &amount) in ids.iter().zip(amounts.iter()) {
+            self._mint(to, id, amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to burn tokens from.
+    /// * `ids` - All token identifiers.
+    /// * `amounts` - All amounts to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least the amount of any of the `ids`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`] - For each pair of `ids` and `amounts`.
+    fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Erc6909::require_equal_arrays_length(&ids, &amounts)?;
+
+        for (&id, &amount) in ids.iter().zip(amounts.iter()) {
+            self._burn(from, id, amount)?;
+        }
+
+        Ok(())
+    }
 }
 
+impl Erc6909Update for Erc6909 {
+    type Error = Error;
+
+    fn erc6909(&self) -> &Erc6909 {
+        self
+    }
 
+    fn erc6909_mut(&mut self) -> &mut Erc6909 {
+        self
+    }
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if !from.is_zero() {
+            let from_balance = self.balance_of(from, id);
+            if from_balance < amount {
+                return Err(Error::InsufficientBalance(
+                    ERC6909InsufficientBalance {
+                        sender: from,
+                        balance: from_balance,
+                        needed: amount,
+                        id,
+                    },
+                ));
+            }
+            self.balances.setter(from).insert(id, from_balance - amount);
+        }
+
+        if !to.is_zero() {
+            self.balances.setter(to).setter(id).add_assign_checked(
+                amount,
+                "should not exceed `U256::MAX` for `balances`",
+            );
+        }
+
+        evm::log(Transfer {
+            caller: msg::sender(),
+            sender: from,
+            receiver: to,
+            id,
+            amount,
+        });
+
+        Ok(())
+    }
+}
 
-// ——————————————————————————————————————————————————————————————————————————
-// motsu-driven Unit tests
-// Run : cargo test -p openzeppelin-stylus --features stylus-test
-// ——————————————————————————————————————————————————————————————————————————
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use stylus_sdk::testing::TestVM;
-    use alloy_primitives::{Address, U256};
-    use crate::token::erc6909::Error;
-    use motsu::prelude::*;
-
-    // use crate::token::erc6909::extensions::composite_token::MyToken;
+    use alloc::vec;
 
+    use alloy_primitives::{uint, Address, FixedBytes, U256};
+    use motsu::prelude::Contract;
 
-    // Core traits:
-    use crate::token::erc6909::traits::{
-        IErc6909,
-        IErc6909Mintable,
-        IErc6909Burnable,
+    use super::{Approval, Erc6909, Erc6909Update, OperatorSet, Transfer};
+    use crate::{
+        token::erc6909::{
+            error::{
+                ERC6909FailedDecreaseAllowance, ERC6909InsufficientAllowance,
+                ERC6909InsufficientBalance, ERC6909InvalidArrayLength,
+                ERC6909InvalidReceiver, ERC6909InvalidSpender, Error,
+            },
+            traits::{
+                IErc6909, IErc6909Allowance, IErc6909Batch, IErc6909Burnable,
+                IErc6909Mintable,
+            },
+        },
+        utils::introspection::erc165::IErc165,
     };
 
-    // Extension traits & types:
-    use crate::token::erc6909::traits::{
-        IErc6909Supply,
-        IErc6909MetadataUri,
-        IErc6909Enumerable,
-    };
-    use crate::token::erc6909::extensions::{
-        metadata_uri::Erc6909MetadataUri,
-        token_supply::Erc6909Supply,
-        enumerable::Erc6909Enumerable,
-    };
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    #[motsu::test]
+    fn zero_address_mint_reverts(contract: Contract<Erc6909>, alice: Address) {
+        let err = contract
+            .sender(alice)
+            .mint(Address::ZERO, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not mint to the zero address");
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn burn_underflow_reverts(contract: Contract<Erc6909>, alice: Address) {
+        let err = contract
+            .sender(alice)
+            .burn(alice, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not burn more than the balance");
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id,
+            }) if sender == alice
+                && balance.is_zero()
+                && needed == uint!(1_U256)
+                && id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn approve_zero_address_reverts(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve(Address::ZERO, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not approve the zero address");
+        assert!(matches!(
+            err,
+            Error::InvalidSpender(ERC6909InvalidSpender { spender })
+                if spender.is_zero()
+        ));
+    }
 
-    /// Helper: fresh core + VM + two addresses + an ID
-    fn fresh_core() -> (Erc6909, TestVM, Address, Address, U256) {
-        let vm    = TestVM::default();
-        let t     = Erc6909::from(&vm);
-        let owner = Address::new([0xAA;20]);
-        let recipient   = Address::new([0xBB;20]);
-        let id    = U256::from(1u64);
-        (t, vm, owner, recipient, id)
+    #[motsu::test]
+    fn transfer_zero_amount_ok(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        let transferred = contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, U256::ZERO)
+            .expect("should transfer zero tokens");
+        assert!(transferred);
     }
 
     #[motsu::test]
-    fn zero_address_mint_reverts() {
-        let (mut t, _, _, _, id) = fresh_core();
-        let zero = Address::new([0u8;20]);
-        let res = t.mint(zero, zero, id, U256::from(1u64));
-        assert_eq!(res, Err(Error::InvalidApprover));
+    fn transfer_moves_callers_balance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, uint!(4_U256))
+            .expect("should transfer Alice's tokens to Bob");
+
+        assert_eq!(
+            uint!(6_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(4_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
     }
 
     #[motsu::test]
-    fn burn_underflow_reverts() {
-        let (mut t, _, owner, _, id) = fresh_core();
-        let res = t.burn(owner, owner, id, U256::from(1u64));
-        assert_eq!(res, Err(Error::InsufficientBalance));
+    fn transfer_from_requires_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not transfer without allowance");
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(ERC6909InsufficientAllowance {
+                spender,
+                allowance,
+                needed,
+                id,
+            }) if spender == bob
+                && allowance.is_zero()
+                && needed == uint!(1_U256)
+                && id == TOKEN_ID
+        ));
+
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(3_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(2_U256))
+            .expect("should transfer Alice's tokens to Bob");
+
+        assert_eq!(
+            uint!(2_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(1_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
     }
 
     #[motsu::test]
-    fn approve_zero_address_reverts() {
-        let (mut t, _, owner, _, id) = fresh_core();
-        let zero = Address::new([0u8;20]);
-        let res = t.approve(owner, zero, id, U256::from(1u64));
-        assert_eq!(res, Err(Error::InvalidApprover));
+    fn transfer_from_keeps_infinite_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, U256::MAX)
+            .expect("should approve Bob");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(4_U256))
+            .expect("should transfer Alice's tokens to Bob");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(6_U256))
+            .expect("should transfer Alice's tokens to Bob again");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+        assert_eq!(
+            U256::MAX,
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
     }
 
     #[motsu::test]
-    fn transfer_zero_amount_ok() {
-        let (mut t, _, owner, recipient, id) = fresh_core();
-        t.mint(owner, owner, id, U256::from(10u64)).unwrap();
-        assert!(t.transfer(owner, recipient, id, U256::ZERO).unwrap());
+    fn increase_and_decrease_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .increase_allowance(bob, TOKEN_ID, uint!(3_U256))
+            .expect("should increase Bob's allowance");
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: uint!(3_U256),
+        });
+        contract
+            .sender(alice)
+            .increase_allowance(bob, TOKEN_ID, uint!(2_U256))
+            .expect("should increase Bob's allowance again");
+        assert_eq!(
+            uint!(5_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .allowance(alice, bob, uint!(2_U256))
+            .is_zero());
+
+        contract
+            .sender(alice)
+            .decrease_allowance(bob, TOKEN_ID, uint!(4_U256))
+            .expect("should decrease Bob's allowance");
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: uint!(1_U256),
+        });
+        assert_eq!(
+            uint!(1_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
     }
 
     #[motsu::test]
-    fn supply_tracks_total() {
-        let (_, vm, owner, _, id) = fresh_core();
-        let mut sup = Erc6909Supply::from(&vm);
-        // mint 7 for owner
-        sup.mint(owner, owner, id, U256::from(7u64)).unwrap();
-        assert_eq!(sup.total_supply(id), U256::from(7u64));
-        // burn 3
-        sup.burn(owner, owner, id, U256::from(3u64)).unwrap();
-        assert_eq!(sup.total_supply(id), U256::from(4u64));
+    fn decrease_allowance_below_zero_reverts(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(1_U256))
+            .expect("should approve Bob");
+
+        let err = contract
+            .sender(alice)
+            .decrease_allowance(bob, TOKEN_ID, uint!(2_U256))
+            .expect_err("should not decrease the allowance below zero");
+        assert!(matches!(
+            err,
+            Error::FailedDecreaseAllowance(ERC6909FailedDecreaseAllowance {
+                spender,
+                id,
+                current_allowance,
+                requested_decrease,
+            }) if spender == bob
+                && id == TOKEN_ID
+                && current_allowance == uint!(1_U256)
+                && requested_decrease == uint!(2_U256)
+        ));
     }
 
     #[motsu::test]
-    fn metadata_round_trip() {
-        let (_, vm, _, _, id) = fresh_core();
-        let mut meta = Erc6909MetadataUri::from(&vm);
-        let owner = Address::new([0xAA;20]);
-        // only nonzero caller can set
-        assert_eq!( meta.set_token_uri(owner, id, b"hello".to_vec()),
-                    Ok(true) );
-        assert_eq!( meta.token_uri(id), b"hello".to_vec() );
+    #[should_panic = "should not exceed `U256::MAX` for `allowance`"]
+    fn increase_allowance_panics_on_overflow(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, U256::MAX)
+            .expect("should approve Bob");
+
+        _ = contract.sender(alice).increase_allowance(
+            bob,
+            TOKEN_ID,
+            uint!(1_U256),
+        );
     }
 
     #[motsu::test]
-    fn enumerable_behaviour() {
-        let (_, vm, _, _, _) = fresh_core();
-        let mut en = Erc6909Enumerable::from(&vm);
-
-        en._record_id(U256::from(10u64));
-        en._record_id(U256::from(20u64));
-        en._record_id(U256::from(30u64));
-
-        assert_eq!(en.total_ids(), U256::from(3u64));
-        assert_eq!(en.id_by_index(U256::from(0u64)), U256::from(10u64));
-        assert_eq!(en.id_by_index(U256::from(1u64)), U256::from(20u64));
-        assert_eq!(en.id_by_index(U256::from(2u64)), U256::from(30u64));    
+    fn transfer_from_by_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as Alice's operator");
+        assert!(contract.sender(alice).is_operator(alice, bob));
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(10_U256))
+            .expect("should transfer Alice's tokens as operator");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn caller_cannot_act_for_another_account(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        let err = contract
+            .sender(bob)
+            .transfer(bob, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not move Alice's tokens");
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id,
+            }) if sender == bob
+                && balance.is_zero()
+                && needed == uint!(1_U256)
+                && id == TOKEN_ID
+        ));
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_by_owner(contract: Contract<Erc6909>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        contract
+            .sender(alice)
+            .burn(alice, TOKEN_ID, uint!(4_U256))
+            .expect("should burn Alice's own tokens");
+
+        assert_eq!(
+            uint!(6_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_by_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as Alice's operator");
+
+        contract
+            .sender(bob)
+            .burn(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should burn Alice's tokens as operator");
+
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_spends_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(5_U256))
+            .expect("should approve Bob");
+
+        contract
+            .sender(bob)
+            .burn(alice, TOKEN_ID, uint!(3_U256))
+            .expect("should burn Alice's tokens with allowance");
+
+        assert_eq!(
+            uint!(7_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(2_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+        contract.assert_emitted(&Transfer {
+            caller: bob,
+            sender: alice,
+            receiver: Address::ZERO,
+            id: TOKEN_ID,
+            amount: uint!(3_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn burn_without_authorization_reverts(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(2_U256))
+            .expect("should approve Bob");
+
+        let err = contract
+            .sender(bob)
+            .burn(alice, TOKEN_ID, uint!(3_U256))
+            .expect_err("should not burn more than the allowance");
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(ERC6909InsufficientAllowance {
+                spender,
+                allowance,
+                needed,
+                id,
+            }) if spender == bob
+                && allowance == uint!(2_U256)
+                && needed == uint!(3_U256)
+                && id == TOKEN_ID
+        ));
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn mint_emits_transfer(contract: Contract<Erc6909>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        contract.assert_emitted(&Transfer {
+            caller: alice,
+            sender: Address::ZERO,
+            receiver: alice,
+            id: TOKEN_ID,
+            amount: uint!(10_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn burn_emits_transfer(contract: Contract<Erc6909>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            ._burn(alice, TOKEN_ID, uint!(3_U256))
+            .expect("should burn Alice's tokens");
+
+        contract.assert_emitted(&Transfer {
+            caller: alice,
+            sender: alice,
+            receiver: Address::ZERO,
+            id: TOKEN_ID,
+            amount: uint!(3_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn transfer_from_emits_transfer_with_caller(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(5_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(5_U256))
+            .expect("should transfer Alice's tokens to Bob");
+
+        contract.assert_emitted(&Transfer {
+            caller: bob,
+            sender: alice,
+            receiver: bob,
+            id: TOKEN_ID,
+            amount: uint!(5_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn approve_emits_approval(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(5_U256))
+            .expect("should approve Bob");
+
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: uint!(5_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn set_operator_emits_operator_set(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as Alice's operator");
+        contract.assert_emitted(&OperatorSet {
+            owner: alice,
+            spender: bob,
+            approved: true,
+        });
+
+        contract
+            .sender(alice)
+            .set_operator(bob, false)
+            .expect("should revoke Bob as Alice's operator");
+        contract.assert_emitted(&OperatorSet {
+            owner: alice,
+            spender: bob,
+            approved: false,
+        });
+    }
+
+    #[motsu::test]
+    fn balance_of_batch_returns_each_balance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let ids = vec![TOKEN_ID, uint!(2_U256), uint!(3_U256)];
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                ids.clone(),
+                vec![uint!(10_U256), uint!(20_U256), uint!(30_U256)],
+            )
+            .expect("should mint a batch for Alice");
+
+        let balances = contract
+            .sender(alice)
+            .balance_of_batch(vec![alice, bob, alice], ids)
+            .expect("should return the balances");
+
+        assert_eq!(vec![uint!(10_U256), U256::ZERO, uint!(30_U256)], balances);
+    }
+
+    #[motsu::test]
+    fn balance_of_batch_reverts_on_length_mismatch(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .balance_of_batch(vec![alice], vec![TOKEN_ID, uint!(2_U256)])
+            .expect_err("should not query arrays of different lengths");
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+                ids_length,
+                values_length,
+            }) if ids_length == uint!(2_U256) && values_length == U256::ONE
+        ));
+    }
+
+    #[motsu::test]
+    fn batch_transfer_from_emits_transfer_per_id(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let ids = vec![TOKEN_ID, uint!(2_U256)];
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                ids.clone(),
+                vec![uint!(10_U256), uint!(20_U256)],
+            )
+            .expect("should mint a batch for Alice");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as operator");
+
+        let result = contract
+            .sender(bob)
+            .batch_transfer_from(
+                alice,
+                bob,
+                ids.clone(),
+                vec![uint!(4_U256), uint!(5_U256)],
+            )
+            .expect("should transfer a batch as operator");
+        assert!(result);
+
+        assert_eq!(
+            vec![uint!(6_U256), uint!(15_U256)],
+            contract
+                .sender(alice)
+                .balance_of_batch(vec![alice, alice], ids.clone())
+                .expect("should return Alice's balances")
+        );
+        assert_eq!(
+            vec![uint!(4_U256), uint!(5_U256)],
+            contract
+                .sender(alice)
+                .balance_of_batch(vec![bob, bob], ids)
+                .expect("should return Bob's balances")
+        );
+        contract.assert_emitted(&Transfer {
+            caller: bob,
+            sender: alice,
+            receiver: bob,
+            id: TOKEN_ID,
+            amount: uint!(4_U256),
+        });
+        contract.assert_emitted(&Transfer {
+            caller: bob,
+            sender: alice,
+            receiver: bob,
+            id: uint!(2_U256),
+            amount: uint!(5_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn batch_transfer_from_spends_allowance_per_id(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![TOKEN_ID, uint!(2_U256)],
+                vec![uint!(10_U256), uint!(10_U256)],
+            )
+            .expect("should mint a batch for Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(5_U256))
+            .expect("should approve Bob");
+
+        let err = contract
+            .sender(bob)
+            .batch_transfer_from(
+                alice,
+                bob,
+                vec![TOKEN_ID, uint!(2_U256)],
+                vec![uint!(5_U256), uint!(1_U256)],
+            )
+            .expect_err("should not transfer an id without allowance");
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(ERC6909InsufficientAllowance {
+                spender,
+                allowance,
+                needed,
+                id,
+            }) if spender == bob
+                && allowance.is_zero()
+                && needed == uint!(1_U256)
+                && id == uint!(2_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn batch_transfer_from_reverts_on_length_mismatch(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint tokens for Alice");
+
+        let err = contract
+            .sender(alice)
+            .batch_transfer_from(
+                alice,
+                bob,
+                vec![TOKEN_ID],
+                vec![uint!(1_U256), uint!(2_U256)],
+            )
+            .expect_err("should not transfer arrays of different lengths");
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+                ids_length,
+                values_length,
+            }) if ids_length == U256::ONE && values_length == uint!(2_U256)
+        ));
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_batch_reverts_on_insufficient_balance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![TOKEN_ID, uint!(2_U256)],
+                vec![uint!(10_U256), uint!(1_U256)],
+            )
+            .expect("should mint a batch for Alice");
+
+        let err = contract
+            .sender(alice)
+            ._burn_batch(
+                alice,
+                vec![TOKEN_ID, uint!(2_U256)],
+                vec![uint!(4_U256), uint!(2_U256)],
+            )
+            .expect_err("should not burn more than the balance");
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id,
+            }) if sender == alice
+                && balance == U256::ONE
+                && needed == uint!(2_U256)
+                && id == uint!(2_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn mint_batch_reverts_for_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._mint_batch(Address::ZERO, vec![TOKEN_ID], vec![uint!(1_U256)])
+            .expect_err("should not mint to the zero address");
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909 as IErc6909>::interface_id();
+        let expected: FixedBytes<4> = 0x0f632fb3_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual = <Erc6909 as IErc6909Batch>::interface_id();
+        let expected: FixedBytes<4> = 0x59e8a408_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual = <Erc6909 as IErc6909Allowance>::interface_id();
+        let expected: FixedBytes<4> = 0x32bf3c71_u32.into();
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<Erc6909>, alice: Address) {
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909 as IErc6909>::interface_id()));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909 as IErc6909Batch>::interface_id()));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909 as IErc6909Allowance>::interface_id()
+            )
+        );
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909 as IErc165>::interface_id()));
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
     }
 }
@@ -1,23 +1,141 @@
-#![no_std]
+//! Errors returned by the ERC-6909 token and its extensions.
+use stylus_sdk::{call::MethodError, prelude::*};
 
-/// Typed error codes for ERC-6909 operations.
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates an error related to the current `balance` of a `sender`.
+        /// Used in transfers.
+        ///
+        /// * `sender` - Address whose tokens are being transferred.
+        /// * `balance` - Current balance for the interacting account.
+        /// * `needed` - Minimum amount required to perform a transfer.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InsufficientBalance(
+            address sender,
+            uint256 balance,
+            uint256 needed,
+            uint256 id
+        );
+
+        /// Indicates a failure with the `spender`’s `allowance`.
+        /// Used in transfers.
+        ///
+        /// * `spender` - Address that may be allowed to operate on tokens
+        ///   without being their owner.
+        /// * `allowance` - Amount of tokens a `spender` is allowed to operate
+        ///   with.
+        /// * `needed` - Minimum amount required to perform a transfer.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InsufficientAllowance(
+            address spender,
+            uint256 allowance,
+            uint256 needed,
+            uint256 id
+        );
+
+        /// Indicates a failure with the `approver` of a token to be approved.
+        /// Used in approvals.
+        ///
+        /// * `approver` - Address initiating an approval operation.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidApprover(address approver);
+
+        /// Indicates a failure with the token `receiver`.
+        /// Used in transfers.
+        ///
+        /// * `receiver` - Address to which tokens are being transferred.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidReceiver(address receiver);
+
+        /// Indicates a failure with the token `sender`.
+        /// Used in transfers.
+        ///
+        /// * `sender` - Address whose tokens are being transferred.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidSender(address sender);
+
+        /// Indicates a failure with the `spender` to be approved.
+        /// Used in approvals.
+        ///
+        /// * `spender` - Address that may be allowed to operate on tokens
+        ///   without being their owner.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidSpender(address spender);
+
+        /// Indicates an array length mismatch between `ids` and `values` in a
+        /// batch operation.
+        ///
+        /// * `ids_length` - Length of the array of token identifiers.
+        /// * `values_length` - Length of the array of token amounts (or
+        ///   accounts, when querying balances).
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidArrayLength(uint256 ids_length, uint256 values_length);
+
+        /// Indicates a failed `decrease_allowance` request.
+        ///
+        /// * `spender` - Address that may be allowed to operate on tokens
+        ///   without being their owner.
+        /// * `id` - Token id as a number.
+        /// * `current_allowance` - Amount of tokens `spender` is allowed to
+        ///   operate with.
+        /// * `requested_decrease` - Requested decrease of the allowance.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909FailedDecreaseAllowance(
+            address spender,
+            uint256 id,
+            uint256 current_allowance,
+            uint256 requested_decrease
+        );
+    }
+}
+
+pub use sol::*;
+
+/// An [`super::Erc6909`] error defined as in the OpenZeppelin Solidity
+/// implementation of [EIP-6909].
 ///
-/// These correspond to revert reasons in the Solidity reference contract,
-/// plus an explicit overflow error for checked arithmetic.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// [EIP-6909]: https://eips.ethereum.org/EIPS/eip-6909
+#[derive(SolidityError, Debug)]
 pub enum Error {
-    /// Caller tried to approve from the zero address.
-    InvalidApprover,
-    /// Caller tried to transfer/mint to the zero address.
-    InvalidReceiver,
-    /// Caller tried to burn/transfer from the zero address.
-    InvalidSender,
-    /// Caller tried to set operator to the zero address.
-    InvalidSpender,
-    /// Balance was insufficient for the requested operation.
-    InsufficientBalance,
-    /// Allowance was insufficient for the requested spend.
-    InsufficientAllowance,
-    /// An addition or subtraction overflow/underflow was detected.
-    ArithmeticOverflow,
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation. Used in batch transfers, mints and burns.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request. Used in allowance
+    /// adjustments.
+    FailedDecreaseAllowance(ERC6909FailedDecreaseAllowance),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
 }
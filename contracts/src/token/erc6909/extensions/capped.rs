@@ -0,0 +1,373 @@
+//! Extension of ERC-6909 that adds a cap to the supply of each token type.
+//!
+//! Every token id can get a cap of its own, and ids without one fall back to
+//! the default cap chosen at construction, if any. Caps can only be lowered,
+//! never raised, so holders can rely on the maximum supply of a token type
+//! once it is set.
+//!
+//! The extension holds no balances of its own. A contract composing it must
+//! call [`Erc6909Capped::_update`] after every balance change of its
+//! [`crate::token::erc6909::extensions::Erc6909Supply`] ledger.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use stylus_sdk::{
+    call::MethodError,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::traits::{IErc6909Capped, IErc6909Supply},
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates an error related to the operation that failed
+        /// because the total supply of token type `id` exceeded its `cap`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `increased_supply` - Total supply the operation would result in.
+        /// * `cap` - Cap on the total supply of `id`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ExceededCap(uint256 id, uint256 increased_supply, uint256 cap);
+
+        /// Indicates an error related to the operation that failed
+        /// because the supplied `cap` is not a valid cap of token type `id`,
+        /// i.e. it is zero, above the current cap, or below the current
+        /// supply.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `cap` - Proposed cap.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidCap(uint256 id, uint256 cap);
+    }
+}
+
+pub use sol::*;
+
+/// An [`Erc6909Capped`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the operation that failed
+    /// because the total supply of a token type exceeded its cap.
+    ExceededCap(ERC6909ExceededCap),
+    /// Indicates an error related to the operation that failed
+    /// because the supplied cap is not a valid cap of a token type.
+    InvalidCap(ERC6909InvalidCap),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Capped`] contract.
+#[storage]
+pub struct Erc6909Capped {
+    /// Cap of token types without a cap of their own. Zero means no default
+    /// cap.
+    pub(crate) default_cap: StorageU256,
+    /// Mapping from token id to its cap. Zero means the id has no cap of its
+    /// own.
+    pub(crate) caps: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+#[implements(IErc6909Capped, IErc165)]
+impl Erc6909Capped {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `default_cap` - Cap of token types without a cap of their own, or zero
+    ///   to leave them uncapped.
+    #[constructor]
+    pub fn constructor(&mut self, default_cap: U256) {
+        self.default_cap.set(default_cap);
+    }
+}
+
+#[public]
+impl IErc6909Capped for Erc6909Capped {
+    fn cap(&self, id: U256) -> U256 {
+        let cap = self.caps.get(id);
+        if !cap.is_zero() {
+            return cap;
+        }
+
+        let default_cap = self.default_cap.get();
+        if default_cap.is_zero() {
+            U256::MAX
+        } else {
+            default_cap
+        }
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Capped {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Capped>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909Capped {
+    /// Hook to be called after every balance change of the token ledger.
+    /// Checks that minting did not push the total supply of `id` above its
+    /// cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `from` - Account tokens were transferred from.
+    /// * `_to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `_amount` - Amount of tokens transferred.
+    /// * `supply` - Read access to a contract providing [`IErc6909Supply`]
+    ///   interface, already updated with the balance change.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededCap`] - If the total supply of `id` exceeds its cap.
+    pub fn _update(
+        &self,
+        from: Address,
+        _to: Address,
+        id: U256,
+        _amount: U256,
+        supply: &impl IErc6909Supply,
+    ) -> Result<(), Error> {
+        if !from.is_zero() {
+            return Ok(());
+        }
+
+        let increased_supply = supply.total_supply(id);
+        let cap = self.cap(id);
+        if increased_supply > cap {
+            return Err(Error::ExceededCap(ERC6909ExceededCap {
+                id,
+                increased_supply,
+                cap,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Sets `cap` as the cap on the total supply of token type `id`, without
+    /// checking the caller's rights.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `cap` - New cap of `id`.
+    /// * `supply` - Read access to a contract providing [`IErc6909Supply`]
+    ///   interface.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidCap`] - If `cap` is zero, above the current cap of
+    ///   `id`, or below the current total supply of `id`.
+    pub fn _set_cap(
+        &mut self,
+        id: U256,
+        cap: U256,
+        supply: &impl IErc6909Supply,
+    ) -> Result<(), Error> {
+        if cap.is_zero() || cap > self.cap(id) || cap < supply.total_supply(id)
+        {
+            return Err(Error::InvalidCap(ERC6909InvalidCap { id, cap }));
+        }
+        self.caps.setter(id).set(cap);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::{
+        erc6909::Erc6909Update, extensions::Erc6909Supply, traits::IErc6909,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    #[storage]
+    struct CappedToken {
+        supply: Erc6909Supply,
+        capped: Erc6909Capped,
+    }
+
+    #[public]
+    #[implements(IErc6909Capped)]
+    impl CappedToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.supply.balance_of(owner, id)
+        }
+
+        fn set_cap(&mut self, id: U256, cap: U256) -> Result<(), Error> {
+            self.capped._set_cap(id, cap, &self.supply)
+        }
+
+        fn mint(
+            &mut self,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            self.supply._mint(to, id, amount).expect("should mint");
+            self.capped._update(Address::ZERO, to, id, amount, &self.supply)
+        }
+    }
+
+    #[public]
+    impl IErc6909Capped for CappedToken {
+        fn cap(&self, id: U256) -> U256 {
+            self.capped.cap(id)
+        }
+    }
+
+    unsafe impl TopLevelStorage for CappedToken {}
+    unsafe impl TopLevelStorage for Erc6909Capped {}
+
+    #[motsu::test]
+    fn uncapped_by_default(contract: Contract<Erc6909Capped>, alice: Address) {
+        assert_eq!(U256::MAX, contract.sender(alice).cap(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn ids_fall_back_to_default_cap(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.capped.constructor(uint!(100_U256));
+        });
+        contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(10_U256))
+            .expect("should set the cap of `TOKEN_ID`");
+
+        assert_eq!(uint!(10_U256), contract.sender(alice).cap(TOKEN_ID));
+        assert_eq!(uint!(100_U256), contract.sender(alice).cap(uint!(2_U256)));
+    }
+
+    #[motsu::test]
+    fn mint_up_to_cap(contract: Contract<CappedToken>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(10_U256))
+            .expect("should set the cap of `TOKEN_ID`");
+
+        contract
+            .sender(alice)
+            .mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint up to the cap");
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn mint_above_cap_reverts(contract: Contract<CappedToken>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(10_U256))
+            .expect("should set the cap of `TOKEN_ID`");
+        contract
+            .sender(alice)
+            .mint(alice, TOKEN_ID, uint!(6_U256))
+            .expect("should mint below the cap");
+
+        let err = contract
+            .sender(alice)
+            .mint(alice, TOKEN_ID, uint!(5_U256))
+            .expect_err("should not mint above the cap");
+        assert!(matches!(
+            err,
+            Error::ExceededCap(ERC6909ExceededCap { id, increased_supply, cap })
+                if id == TOKEN_ID
+                    && increased_supply == uint!(11_U256)
+                    && cap == uint!(10_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn cap_can_only_be_lowered(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(10_U256))
+            .expect("should set the cap of `TOKEN_ID`");
+
+        let err = contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(11_U256))
+            .expect_err("should not raise the cap");
+        assert!(matches!(
+            err,
+            Error::InvalidCap(ERC6909InvalidCap { id, cap })
+                if id == TOKEN_ID && cap == uint!(11_U256)
+        ));
+
+        contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(8_U256))
+            .expect("should lower the cap");
+        assert_eq!(uint!(8_U256), contract.sender(alice).cap(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn cap_cannot_be_zero_or_below_supply(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .mint(alice, TOKEN_ID, uint!(5_U256))
+            .expect("should mint uncapped tokens");
+
+        let err = contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, U256::ZERO)
+            .expect_err("should not set a zero cap");
+        assert!(matches!(
+            err,
+            Error::InvalidCap(ERC6909InvalidCap { cap, .. }) if cap.is_zero()
+        ));
+
+        let err = contract
+            .sender(alice)
+            .set_cap(TOKEN_ID, uint!(4_U256))
+            .expect_err("should not set a cap below the supply");
+        assert!(matches!(
+            err,
+            Error::InvalidCap(ERC6909InvalidCap { cap, .. })
+                if cap == uint!(4_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Capped as IErc6909Capped>::interface_id();
+        let expected: FixedBytes<4> = 0xff2ad8e4_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,740 @@
+//! Extension of ERC-6909 that wraps ERC-721 and ERC-1155 tokens into claims.
+//!
+//! Tokens safely transferred to the contract are wrapped into ERC-6909 claims
+//! minted to their previous owner. The claims of token `token_id` of
+//! `collection` have the deterministic id [`collection_token_id`], so
+//! tokens of every collection can be held in a single ERC-6909 ledger, next
+//! to e.g. the claims of an
+//! [`crate::token::erc6909::extensions::Erc6909Wrapper`]. Claims are
+//! burned to unwrap the tokens again through the `safeTransferFrom` of their
+//! collection.
+//!
+//! A contract composing the extension implements
+//! [`IErc721Receiver`] and [`IErc1155Receiver`] in terms of
+//! [`Erc6909CollectionWrapping`]. Since any contract can call the receiver
+//! hooks, the claims of a collection are only as trustworthy as the
+//! collection itself, the same way its tokens are.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    contract,
+    prelude::*,
+};
+
+use crate::token::{
+    erc1155::{
+        interface::Erc1155Interface, IErc1155Receiver,
+        BATCH_TRANSFER_FN_SELECTOR, SINGLE_TRANSFER_FN_SELECTOR,
+    },
+    erc6909::{
+        self,
+        erc6909::Erc6909Update,
+        error::{self, ERC6909InvalidReceiver},
+    },
+    erc721::{
+        interface::Erc721Interface, IErc721Receiver, RECEIVER_FN_SELECTOR,
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that token `token_id` of `collection` couldn't be
+        /// transferred out of the contract.
+        ///
+        /// * `collection` - Address of the ERC-721 or ERC-1155 contract.
+        /// * `token_id` - Token id in `collection`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909FailedUnwrap(address collection, uint256 token_id);
+    }
+}
+
+/// An [`Erc6909CollectionWrapping`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(error::ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(error::ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(error::ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+    /// A wrapped token couldn't be transferred out of the contract.
+    FailedUnwrap(ERC6909FailedUnwrap),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns the id of the claims of token `token_id` of `collection`, computed
+/// as `keccak256(abi.encode(collection, token_id))`.
+///
+/// # Arguments
+///
+/// * `collection` - Address of the ERC-721 or ERC-1155 contract.
+/// * `token_id` - Token id in `collection`.
+#[must_use]
+pub fn collection_token_id(collection: Address, token_id: U256) -> U256 {
+    let hash = keccak256(
+        [collection.into_word().0, token_id.to_be_bytes::<32>()].concat(),
+    );
+    U256::from_be_bytes(hash.0)
+}
+
+/// Interface of an ERC-6909 token unwrapping the ERC-721 and ERC-1155 tokens
+/// it holds.
+#[interface_id]
+pub trait IErc6909CollectionWrapper {
+    /// The error type associated to the trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Burns the claim of ERC-721 token `token_id` of `collection` held by
+    /// the caller and safely transfers the token to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `collection` - Address of the ERC-721 contract.
+    /// * `token_id` - Token id in `collection`.
+    /// * `account` - The account to transfer the token to.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If the caller doesn't hold the claim.
+    /// * [`Error::FailedUnwrap`] - If the transfer of the token fails.
+    fn withdraw_erc721_to(
+        &mut self,
+        collection: Address,
+        token_id: U256,
+        account: Address,
+    ) -> Result<bool, Self::Error>;
+
+    /// Burns `value` claims of ERC-1155 token `token_id` of `collection` held
+    /// by the caller and safely transfers `value` tokens to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `collection` - Address of the ERC-1155 contract.
+    /// * `token_id` - Token id in `collection`.
+    /// * `account` - The account to transfer the tokens to.
+    /// * `value` - The amount of tokens to unwrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If the caller doesn't have enough
+    ///   claims.
+    /// * [`Error::FailedUnwrap`] - If the transfer of the tokens fails.
+    fn withdraw_erc1155_to(
+        &mut self,
+        collection: Address,
+        token_id: U256,
+        account: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error>;
+}
+
+/// ERC-6909 token minting claims of the ERC-721 and ERC-1155 tokens it
+/// receives.
+///
+/// Layered over the [`Erc6909Update`] hook, so the claims go through every
+/// other extension of the contract.
+pub trait Erc6909CollectionWrapping:
+    Erc6909Update + TopLevelStorage + Sized
+where
+    Self::Error: From<Error>,
+{
+    /// Mints the claim of ERC-721 token `token_id` of `collection` to `from`.
+    /// Meant to be called from [`IErc721Receiver::on_erc721_received`], with
+    /// `msg::sender()` as `collection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `collection` - Address of the ERC-721 contract.
+    /// * `from` - Previous owner of the token.
+    /// * `token_id` - Token id in `collection`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `from` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _on_erc721_received(
+        &mut self,
+        collection: Address,
+        from: Address,
+        token_id: U256,
+    ) -> Result<FixedBytes<4>, Self::Error> {
+        self._mint(
+            from,
+            collection_token_id(collection, token_id),
+            U256::from(1),
+        )?;
+        Ok(RECEIVER_FN_SELECTOR.into())
+    }
+
+    /// Mints `value` claims of ERC-1155 token `token_id` of `collection` to
+    /// `from`. Meant to be called from
+    /// [`IErc1155Receiver::on_erc1155_received`], with `msg::sender()` as
+    /// `collection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `collection` - Address of the ERC-1155 contract.
+    /// * `from` - Previous owner of the tokens.
+    /// * `token_id` - Token id in `collection`.
+    /// * `value` - The amount of tokens received.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `from` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _on_erc1155_received(
+        &mut self,
+        collection: Address,
+        from: Address,
+        token_id: U256,
+        value: U256,
+    ) -> Result<FixedBytes<4>, Self::Error> {
+        self._mint(from, collection_token_id(collection, token_id), value)?;
+        Ok(SINGLE_TRANSFER_FN_SELECTOR.into())
+    }
+
+    /// Mints `values` claims of ERC-1155 tokens `token_ids` of `collection`
+    /// to `from`. Meant to be called from
+    /// [`IErc1155Receiver::on_erc1155_batch_received`], with `msg::sender()`
+    /// as `collection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `collection` - Address of the ERC-1155 contract.
+    /// * `from` - Previous owner of the tokens.
+    /// * `token_ids` - Token ids in `collection`.
+    /// * `values` - The amounts of tokens received.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If the lengths of `token_ids` and
+    ///   `values` differ.
+    /// * [`Error::InvalidReceiver`] - If `from` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _on_erc1155_batch_received(
+        &mut self,
+        collection: Address,
+        from: Address,
+        token_ids: Vec<U256>,
+        values: Vec<U256>,
+    ) -> Result<FixedBytes<4>, Self::Error> {
+        let ids = token_ids
+            .into_iter()
+            .map(|token_id| collection_token_id(collection, token_id))
+            .collect();
+        self._mint_batch(from, ids, values)?;
+        Ok(BATCH_TRANSFER_FN_SELECTOR.into())
+    }
+
+    /// Burns the claim of ERC-721 token `token_id` of `collection` from
+    /// `sender` and safely transfers the token to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account unwrapping the token.
+    /// * `collection` - Address of the ERC-721 contract.
+    /// * `token_id` - Token id in `collection`.
+    /// * `account` - The account to transfer the token to.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If `sender` doesn't hold the claim.
+    /// * [`Error::FailedUnwrap`] - If the transfer of the token fails.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _withdraw_erc721_to(
+        &mut self,
+        sender: Address,
+        collection: Address,
+        token_id: U256,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        let contract_address = contract::address();
+        if account == contract_address {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: account,
+            })
+            .into());
+        }
+
+        self._burn(
+            sender,
+            collection_token_id(collection, token_id),
+            U256::from(1),
+        )?;
+
+        Erc721Interface::new(collection)
+            .safe_transfer_from(
+                Call::new_in(self),
+                contract_address,
+                account,
+                token_id,
+                vec![].into(),
+            )
+            .map_err(|_| {
+                Error::FailedUnwrap(ERC6909FailedUnwrap {
+                    collection,
+                    token_id,
+                })
+            })?;
+
+        Ok(())
+    }
+
+    /// Burns `value` claims of ERC-1155 token `token_id` of `collection` from
+    /// `sender` and safely transfers `value` tokens to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account unwrapping the tokens.
+    /// * `collection` - Address of the ERC-1155 contract.
+    /// * `token_id` - Token id in `collection`.
+    /// * `account` - The account to transfer the tokens to.
+    /// * `value` - The amount of tokens to unwrap.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If `sender` doesn't have enough
+    ///   claims.
+    /// * [`Error::FailedUnwrap`] - If the transfer of the tokens fails.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _withdraw_erc1155_to(
+        &mut self,
+        sender: Address,
+        collection: Address,
+        token_id: U256,
+        account: Address,
+        value: U256,
+    ) -> Result<(), Self::Error> {
+        let contract_address = contract::address();
+        if account == contract_address {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: account,
+            })
+            .into());
+        }
+
+        self._burn(sender, collection_token_id(collection, token_id), value)?;
+
+        Erc1155Interface::new(collection)
+            .safe_transfer_from(
+                Call::new_in(self),
+                contract_address,
+                account,
+                token_id,
+                value,
+                vec![].into(),
+            )
+            .map_err(|_| {
+                Error::FailedUnwrap(ERC6909FailedUnwrap {
+                    collection,
+                    token_id,
+                })
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, FixedBytes};
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::Bytes, msg};
+
+    use super::*;
+    use crate::token::{
+        erc1155::{Erc1155, IErc1155},
+        erc6909::{erc6909::Erc6909, IErc6909},
+        erc721::{Erc721, IErc721},
+    };
+
+    const TOKEN_ID: U256 = uint!(7_U256);
+
+    #[storage]
+    struct CollectionToken {
+        erc6909: Erc6909,
+    }
+
+    #[public]
+    #[implements(
+        IErc721Receiver<Error = Error>,
+        IErc1155Receiver<Error = Error>,
+        IErc6909CollectionWrapper<Error = Error>
+    )]
+    impl CollectionToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.erc6909.balance_of(owner, id)
+        }
+    }
+
+    #[public]
+    impl IErc721Receiver for CollectionToken {
+        type Error = Error;
+
+        #[selector(name = "onERC721Received")]
+        fn on_erc721_received(
+            &mut self,
+            _operator: Address,
+            from: Address,
+            token_id: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Error> {
+            self._on_erc721_received(msg::sender(), from, token_id)
+        }
+    }
+
+    #[public]
+    impl IErc1155Receiver for CollectionToken {
+        type Error = Error;
+
+        #[selector(name = "onERC1155Received")]
+        fn on_erc1155_received(
+            &mut self,
+            _operator: Address,
+            from: Address,
+            id: U256,
+            value: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Error> {
+            self._on_erc1155_received(msg::sender(), from, id, value)
+        }
+
+        #[selector(name = "onERC1155BatchReceived")]
+        fn on_erc1155_batch_received(
+            &mut self,
+            _operator: Address,
+            from: Address,
+            ids: Vec<U256>,
+            values: Vec<U256>,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Error> {
+            self._on_erc1155_batch_received(msg::sender(), from, ids, values)
+        }
+    }
+
+    #[public]
+    impl IErc6909CollectionWrapper for CollectionToken {
+        type Error = Error;
+
+        fn withdraw_erc721_to(
+            &mut self,
+            collection: Address,
+            token_id: U256,
+            account: Address,
+        ) -> Result<bool, Error> {
+            self._withdraw_erc721_to(
+                msg::sender(),
+                collection,
+                token_id,
+                account,
+            )?;
+            Ok(true)
+        }
+
+        fn withdraw_erc1155_to(
+            &mut self,
+            collection: Address,
+            token_id: U256,
+            account: Address,
+            value: U256,
+        ) -> Result<bool, Error> {
+            self._withdraw_erc1155_to(
+                msg::sender(),
+                collection,
+                token_id,
+                account,
+                value,
+            )?;
+            Ok(true)
+        }
+    }
+
+    unsafe impl TopLevelStorage for CollectionToken {}
+
+    impl Erc6909Update for CollectionToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.erc6909._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909CollectionWrapping for CollectionToken {}
+
+    #[motsu::test]
+    fn collection_token_ids_are_distinct(alice: Address, bob: Address) {
+        let id = collection_token_id(alice, TOKEN_ID);
+        assert_eq!(id, collection_token_id(alice, TOKEN_ID));
+        assert_ne!(id, collection_token_id(bob, TOKEN_ID));
+        assert_ne!(id, collection_token_id(alice, TOKEN_ID + U256::from(1)));
+    }
+
+    #[motsu::test]
+    fn wraps_and_unwraps_erc721(
+        contract: Contract<CollectionToken>,
+        erc721: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+    ) {
+        erc721.sender(alice)._mint(alice, TOKEN_ID).motsu_expect("should mint");
+        erc721
+            .sender(alice)
+            .safe_transfer_from(alice, contract.address(), TOKEN_ID)
+            .motsu_expect("should wrap");
+
+        let id = collection_token_id(erc721.address(), TOKEN_ID);
+        assert_eq!(U256::from(1), contract.sender(alice).balance_of(alice, id));
+        assert_eq!(
+            contract.address(),
+            erc721.sender(alice).owner_of(TOKEN_ID).motsu_unwrap()
+        );
+
+        contract
+            .sender(alice)
+            .withdraw_erc721_to(erc721.address(), TOKEN_ID, bob)
+            .motsu_expect("should unwrap");
+
+        assert_eq!(U256::ZERO, contract.sender(alice).balance_of(alice, id));
+        assert_eq!(bob, erc721.sender(alice).owner_of(TOKEN_ID).motsu_unwrap());
+    }
+
+    #[motsu::test]
+    fn wraps_and_unwraps_erc1155(
+        contract: Contract<CollectionToken>,
+        erc1155: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let value = uint!(10_U256);
+        let unwrapped = uint!(4_U256);
+        erc1155
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, value, &vec![].into())
+            .motsu_expect("should mint");
+        erc1155
+            .sender(alice)
+            .safe_transfer_from(
+                alice,
+                contract.address(),
+                TOKEN_ID,
+                value,
+                vec![].into(),
+            )
+            .motsu_expect("should wrap");
+
+        let id = collection_token_id(erc1155.address(), TOKEN_ID);
+        assert_eq!(value, contract.sender(alice).balance_of(alice, id));
+
+        contract
+            .sender(alice)
+            .withdraw_erc1155_to(erc1155.address(), TOKEN_ID, bob, unwrapped)
+            .motsu_expect("should unwrap");
+
+        assert_eq!(
+            value - unwrapped,
+            contract.sender(alice).balance_of(alice, id)
+        );
+        assert_eq!(unwrapped, erc1155.sender(alice).balance_of(bob, TOKEN_ID));
+        assert_eq!(
+            value - unwrapped,
+            erc1155.sender(alice).balance_of(contract.address(), TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn wraps_erc1155_batch(
+        contract: Contract<CollectionToken>,
+        erc1155: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let token_ids = vec![TOKEN_ID, uint!(8_U256)];
+        let values = vec![uint!(3_U256), uint!(5_U256)];
+        erc1155
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                token_ids.clone(),
+                values.clone(),
+                &vec![].into(),
+            )
+            .motsu_expect("should mint");
+        erc1155
+            .sender(alice)
+            .safe_batch_transfer_from(
+                alice,
+                contract.address(),
+                token_ids.clone(),
+                values.clone(),
+                vec![].into(),
+            )
+            .motsu_expect("should wrap");
+
+        for (token_id, value) in token_ids.into_iter().zip(values) {
+            let id = collection_token_id(erc1155.address(), token_id);
+            assert_eq!(value, contract.sender(alice).balance_of(alice, id));
+        }
+    }
+
+    #[motsu::test]
+    fn withdraw_reverts_without_claim(
+        contract: Contract<CollectionToken>,
+        erc721: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+    ) {
+        erc721.sender(alice)._mint(alice, TOKEN_ID).motsu_expect("should mint");
+        erc721
+            .sender(alice)
+            .safe_transfer_from(alice, contract.address(), TOKEN_ID)
+            .motsu_expect("should wrap");
+
+        let err = contract
+            .sender(bob)
+            .withdraw_erc721_to(erc721.address(), TOKEN_ID, bob)
+            .motsu_expect_err("should return Error::InsufficientBalance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(error::ERC6909InsufficientBalance {
+                sender,
+                ..
+            }) if sender == bob
+        ));
+        assert_eq!(
+            contract.address(),
+            erc721.sender(alice).owner_of(TOKEN_ID).motsu_unwrap()
+        );
+    }
+
+    #[motsu::test]
+    fn withdraw_reverts_when_collection_fails(
+        contract: Contract<CollectionToken>,
+        erc721: Contract<Erc721>,
+        alice: Address,
+    ) {
+        // Claims minted by a caller posing as the collection are not backed
+        // by any token.
+        contract
+            .sender(erc721.address())
+            .on_erc721_received(alice, alice, TOKEN_ID, vec![].into())
+            .motsu_expect("should mint claim");
+
+        let err = contract
+            .sender(alice)
+            .withdraw_erc721_to(erc721.address(), TOKEN_ID, alice)
+            .motsu_expect_err("should return Error::FailedUnwrap");
+
+        assert!(matches!(
+            err,
+            Error::FailedUnwrap(ERC6909FailedUnwrap { collection, token_id })
+                if collection == erc721.address() && token_id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_ids() {
+        let actual = <CollectionToken as IErc721Receiver>::interface_id();
+        let expected: FixedBytes<4> = 0x150b7a02_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual = <CollectionToken as IErc1155Receiver>::interface_id();
+        let expected: FixedBytes<4> = 0x4e2312e0_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual =
+            <CollectionToken as IErc6909CollectionWrapper>::interface_id();
+        let expected: FixedBytes<4> = 0xbb7370ef_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
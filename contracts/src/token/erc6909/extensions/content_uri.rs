@@ -0,0 +1,178 @@
+//! Optional Content URI extension of the ERC-6909 standard, as defined in the
+//! [EIP].
+//!
+//! [EIP]: https://eips.ethereum.org/EIPS/eip-6909#content-uri-extension
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{FixedBytes, U256};
+use stylus_sdk::{evm, prelude::*, storage::StorageString};
+
+use crate::{
+    token::erc6909::traits::IErc6909ContentUri,
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the contract URI is changed.
+        ///
+        /// The event comes from [ERC-7572].
+        ///
+        /// [ERC-7572]: https://eips.ethereum.org/EIPS/eip-7572
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ContractURIUpdated();
+    }
+}
+
+pub use sol::*;
+
+/// State of an [`Erc6909ContentUri`] contract.
+#[storage]
+pub struct Erc6909ContentUri {
+    /// URI of the contract-level metadata.
+    pub(crate) contract_uri: StorageString,
+    /// Used as the URI for all token types by relying on ID substitution,
+    /// e.g. https://token-cdn-domain/{id}.json.
+    pub(crate) uri: StorageString,
+}
+
+#[public]
+#[implements(IErc6909ContentUri, IErc165)]
+impl Erc6909ContentUri {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `contract_uri` - URI of the contract-level metadata.
+    /// * `uri` - URI of all token types, possibly containing `{id}`.
+    #[constructor]
+    pub fn constructor(&mut self, contract_uri: String, uri: String) {
+        self.contract_uri.set_str(contract_uri);
+        self.uri.set_str(uri);
+    }
+}
+
+#[public]
+impl IErc6909ContentUri for Erc6909ContentUri {
+    #[selector(name = "contractURI")]
+    fn contract_uri(&self) -> String {
+        self.contract_uri.get_string()
+    }
+
+    /// This implementation returns the same URI for all token types.
+    /// Clients calling this function must replace the `{id}` substring with
+    /// the actual token type ID.
+    #[selector(name = "tokenURI")]
+    fn token_uri(&self, _id: U256) -> String {
+        self.uri.get_string()
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909ContentUri {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909ContentUri>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909ContentUri {
+    /// Sets `contract_uri` as the URI of the contract-level metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `contract_uri` - New contract URI.
+    ///
+    /// # Events
+    ///
+    /// * [`ContractURIUpdated`].
+    pub fn _set_contract_uri(&mut self, contract_uri: String) {
+        self.contract_uri.set_str(contract_uri);
+        evm::log(ContractURIUpdated {});
+    }
+
+    /// Sets `uri` as the URI of all token types.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `uri` - New URI of all token types, possibly containing `{id}`.
+    pub fn _set_uri(&mut self, uri: String) {
+        self.uri.set_str(uri);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, Address};
+    use motsu::prelude::Contract;
+    use stylus_sdk::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909ContentUri {}
+
+    const CONTRACT_URI: &str = "https://token-cdn-domain/contract.json";
+    const URI: &str = "https://token-cdn-domain/{id}.json";
+
+    #[motsu::test]
+    fn token_uri_ignores_token_id(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .constructor(CONTRACT_URI.to_owned(), URI.to_owned());
+
+        assert_eq!(URI, contract.sender(alice).token_uri(uint!(1_U256)));
+        assert_eq!(URI, contract.sender(alice).token_uri(uint!(2_U256)));
+        assert_eq!(CONTRACT_URI, contract.sender(alice).contract_uri());
+    }
+
+    #[motsu::test]
+    fn set_contract_uri_emits_event(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        let contract_uri = "ipfs://contract";
+        contract.init(alice, |contract| {
+            contract._set_contract_uri(contract_uri.to_owned());
+        });
+
+        assert_eq!(contract_uri, contract.sender(alice).contract_uri());
+        contract.assert_emitted(&ContractURIUpdated {});
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909ContentUri as IErc6909ContentUri>::interface_id();
+        let expected: FixedBytes<4> = 0x20d88258_u32.into();
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ContentUri as IErc6909ContentUri>::interface_id()
+        ));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909ContentUri as IErc165>::interface_id()
+            )
+        );
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
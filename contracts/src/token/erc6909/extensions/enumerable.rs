@@ -1,153 +1,1023 @@
-#![no_std]
-
+//! Extension of ERC-6909 that keeps track of every token id ever minted, of
+//! the token ids each account holds, and of the accounts holding each token
+//! id.
+//!
+//! The extension holds no balances of its own. A contract composing it must
+//! call [`Erc6909Enumerable::_update`] after every balance change of its
+//! [`crate::token::erc6909::Erc6909`] ledger.
+//!
+//! Recorded ids are kept forever by default. Calling
+//! [`Erc6909Enumerable::_set_prune_on_zero_supply`] makes the extension drop
+//! an id once a burn leaves it without holders, i.e. once its supply reaches
+//! zero, so the list of ids only holds token types in circulation.
 use alloc::{vec, vec::Vec};
-use core::convert::TryInto;
 
-use alloy_primitives::U256;
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    host::VM,
-    prelude::{storage, HostAccess, StorageType},
-    storage::{StorageMap, StorageUint, StorageVec},
+    call::MethodError,
+    prelude::*,
+    storage::{
+        StorageAddress, StorageBool, StorageMap, StorageU256, StorageVec,
+    },
+};
+
+use crate::{
+    token::erc6909::traits::{
+        IErc6909, IErc6909Enumerable, IErc6909HolderEnumerable,
+        IErc6909OwnerEnumerable,
+    },
+    utils::introspection::erc165::IErc165,
 };
 
-use crate::token::erc6909::traits::IErc6909Enumerable;
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates an error when a list of token ids or holders is queried
+        /// at an `index` past its end.
+        ///
+        /// * `index` - Queried index.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909OutOfBoundsIndex(uint256 index);
+    }
+}
+
+pub use sol::*;
+
+/// An [`Erc6909Enumerable`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error when a list of token ids or holders is queried at
+    /// an index past its end.
+    OutOfBoundsIndex(ERC6909OutOfBoundsIndex),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
 
-/// Enumerable extension: tracks all unique IDs and their indices.
+/// State of an [`Erc6909Enumerable`] contract.
 #[storage]
 pub struct Erc6909Enumerable {
-    /// List of token IDs in insertion order.
-    all_ids: StorageVec<StorageUint<256, 4>>,
-    /// Mapping from token ID → its index in `all_ids`.
-    index_of: StorageMap<U256, StorageUint<256, 4>>,
+    /// Recorded token ids, in the order they were first minted unless some
+    /// were pruned.
+    pub(crate) all_ids: StorageVec<StorageU256>,
+    /// Mapping from token id to its position in `all_ids`, plus one.
+    /// Zero means the id is not recorded.
+    pub(crate) index_of: StorageMap<U256, StorageU256>,
+    /// Whether ids are removed from `all_ids` once their supply reaches zero.
+    pub(crate) prune_on_zero_supply: StorageBool,
+    /// Mapping from owner to the token ids it holds a non-zero balance of.
+    pub(crate) owned_ids: StorageMap<Address, StorageVec<StorageU256>>,
+    /// Mapping from owner to a mapping from token id to its position in
+    /// `owned_ids`, plus one. Zero means the owner doesn't hold the id.
+    pub(crate) owned_ids_index:
+        StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Mapping from token id to the accounts holding a non-zero balance of
+    /// it.
+    pub(crate) holders: StorageMap<U256, StorageVec<StorageAddress>>,
+    /// Mapping from token id to a mapping from holder to its position in
+    /// `holders`, plus one. Zero means the account doesn't hold the id.
+    pub(crate) holders_index:
+        StorageMap<U256, StorageMap<Address, StorageU256>>,
+}
+
+#[public]
+#[implements(
+    IErc6909Enumerable,
+    IErc6909OwnerEnumerable,
+    IErc6909HolderEnumerable,
+    IErc165
+)]
+impl Erc6909Enumerable {}
+
+#[public]
+impl IErc6909Enumerable for Erc6909Enumerable {
+    type Error = Error;
+
+    fn total_ids(&self) -> U256 {
+        U256::from(self.all_ids.len())
+    }
+
+    fn id_by_index(&self, index: U256) -> Result<U256, Error> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.all_ids.get(i))
+            .ok_or(out_of_bounds(index))
+    }
+
+    fn ids_range(&self, offset: U256, limit: U256) -> Vec<U256> {
+        let (start, end) = range_bounds(offset, limit, self.all_ids.len());
+        (start..end).filter_map(|index| self.all_ids.get(index)).collect()
+    }
+}
+
+#[public]
+impl IErc6909OwnerEnumerable for Erc6909Enumerable {
+    type Error = Error;
+
+    fn ids_of_owner(&self, owner: Address) -> Vec<U256> {
+        let owned_ids = self.owned_ids.get(owner);
+        (0..owned_ids.len()).filter_map(|index| owned_ids.get(index)).collect()
+    }
+
+    fn id_of_owner_by_index(
+        &self,
+        owner: Address,
+        index: U256,
+    ) -> Result<U256, Error> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.owned_ids.get(owner).get(i))
+            .ok_or(out_of_bounds(index))
+    }
+
+    fn owner_count(&self, owner: Address) -> U256 {
+        U256::from(self.owned_ids.get(owner).len())
+    }
+}
+
+#[public]
+impl IErc6909HolderEnumerable for Erc6909Enumerable {
+    type Error = Error;
+
+    fn holders_of(&self, id: U256) -> Vec<Address> {
+        let holders = self.holders.get(id);
+        (0..holders.len()).filter_map(|index| holders.get(index)).collect()
+    }
+
+    fn holder_by_index(&self, id: U256, index: U256) -> Result<Address, Error> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.holders.get(id).get(i))
+            .ok_or(out_of_bounds(index))
+    }
+
+    fn holder_count(&self, id: U256) -> U256 {
+        U256::from(self.holders.get(id).len())
+    }
+
+    fn holders_range(
+        &self,
+        id: U256,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<Address> {
+        let holders = self.holders.get(id);
+        let (start, end) = range_bounds(offset, limit, holders.len());
+        (start..end).filter_map(|index| holders.get(index)).collect()
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Enumerable {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Enumerable>::interface_id() == interface_id
+            || <Self as IErc6909OwnerEnumerable>::interface_id() == interface_id
+            || <Self as IErc6909HolderEnumerable>::interface_id()
+                == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
 }
 
 impl Erc6909Enumerable {
-    /// Internal hook: record a new ID if not seen before.
-    pub fn _record_id(&mut self, id: U256) {
-        // 1) never record the zero‐ID
-        if id == U256::ZERO {
+    /// Hook to be called after every balance change of the token ledger.
+    /// Records `id` when tokens of that type are minted, and updates the ids
+    /// held by `from` and `to` along with the holders of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account tokens were transferred from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens transferred.
+    /// * `erc6909` - Read access to a contract providing [`IErc6909`]
+    ///   interface, already updated with the balance change.
+    pub fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        erc6909: &impl IErc6909,
+    ) {
+        if amount.is_zero() || from == to {
             return;
         }
 
-        // 2) if our map already holds a nonzero “sentinel,” skip
-        //    (we store idx+1 below so that sentinel==0 always means “not seen”)
-        if self.index_of.get(id) != U256::ZERO {
+        if from.is_zero() {
+            self._record_id(id);
+        }
+
+        if !from.is_zero() && erc6909.balance_of(from, id).is_zero() {
+            self._remove_id_from_owner_enumeration(from, id);
+            self._remove_holder_from_id_enumeration(id, from);
+        }
+
+        if !to.is_zero() && erc6909.balance_of(to, id) == amount {
+            self._add_id_to_owner_enumeration(to, id);
+            self._add_holder_to_id_enumeration(id, to);
+        }
+
+        // Only a burn can bring the supply of `id` down to zero.
+        if to.is_zero()
+            && self.prune_on_zero_supply.get()
+            && self.holders.get(id).is_empty()
+        {
+            self._remove_id(id);
+        }
+    }
+
+    /// Records `id` if it was not recorded before.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    pub fn _record_id(&mut self, id: U256) {
+        if !self.index_of.get(id).is_zero() {
             return;
         }
 
-        // 3) append into our Vec, then record idx+1 in the map
-        let idx = U256::from(self.all_ids.len());
         self.all_ids.push(id);
-        self.index_of.insert(id, idx + U256::ONE);    }
-}
+        self.index_of.insert(id, U256::from(self.all_ids.len()));
+    }
 
-impl IErc6909Enumerable for Erc6909Enumerable {
-    /// Returns the total number of unique IDs recorded.
-    fn total_ids(&self) -> U256 {
-        U256::from(self.all_ids.len())
+    /// Sets whether ids are removed from the list of recorded ids once their
+    /// supply reaches zero. Ids are recorded again when minted anew.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `prune` - Whether to prune ids with zero supply.
+    pub fn _set_prune_on_zero_supply(&mut self, prune: bool) {
+        self.prune_on_zero_supply.set(prune);
+    }
+
+    /// Removes `id` from the list of recorded ids, if there.
+    ///
+    /// This has O(1) time complexity, but alters the order of the recorded
+    /// ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    pub fn _remove_id(&mut self, id: U256) {
+        let position = self.index_of.get(id);
+        if position.is_zero() {
+            return;
+        }
+
+        // Swap and pop, as in [`Self::_remove_id_from_owner_enumeration`].
+        let index = usize::try_from(position - U256::ONE)
+            .expect("position should fit in `usize`");
+        let last_index = self.all_ids.len() - 1;
+
+        if index != last_index {
+            let last_id = self
+                .all_ids
+                .get(last_index)
+                .expect("last id should be in bounds");
+
+            self.all_ids
+                .setter(index)
+                .expect("id to delete should be in bounds")
+                .set(last_id);
+            self.index_of.insert(last_id, position);
+        }
+
+        self.all_ids.pop();
+        self.index_of.delete(id);
     }
 
-    /// Returns the token ID at the given index, or zero if out of bounds.
-    fn id_by_index(&self, index: U256) -> U256 {
-        // Convert U256 index to usize by taking the low 8 bytes
-        let raw: [u8; 32] = index.to_le_bytes();
-        let idx_u64 = u64::from_le_bytes(raw[0..8].try_into().unwrap());
-        let idx_usize = idx_u64 as usize;
+    /// Adds `id` to the ids held by `owner`, if not already there.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `id` - Token id as a number.
+    pub fn _add_id_to_owner_enumeration(&mut self, owner: Address, id: U256) {
+        if !self.owned_ids_index.get(owner).get(id).is_zero() {
+            return;
+        }
+
+        let mut owned_ids = self.owned_ids.setter(owner);
+        owned_ids.push(id);
+        let position = U256::from(owned_ids.len());
+        self.owned_ids_index.setter(owner).insert(id, position);
+    }
+
+    /// Removes `id` from the ids held by `owner`, if there.
+    ///
+    /// This has O(1) time complexity, but alters the order of the ids held
+    /// by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `id` - Token id as a number.
+    pub fn _remove_id_from_owner_enumeration(
+        &mut self,
+        owner: Address,
+        id: U256,
+    ) {
+        let position = self.owned_ids_index.get(owner).get(id);
+        if position.is_zero() {
+            return;
+        }
+
+        // To prevent a gap in the owner's ids array, we store the last id in
+        // the index of the id to delete, and then delete the last slot (swap
+        // and pop).
+        let mut owned_ids = self.owned_ids.setter(owner);
+        let index = usize::try_from(position - U256::ONE)
+            .expect("position should fit in `usize`");
+        let last_index = owned_ids.len() - 1;
+
+        // When the id to delete is the last id, the swap operation is
+        // unnecessary.
+        if index != last_index {
+            let last_id =
+                owned_ids.get(last_index).expect("last id should be in bounds");
+
+            // Move the last id to the slot of the to-delete id.
+            owned_ids
+                .setter(index)
+                .expect("id to delete should be in bounds")
+                .set(last_id);
+            // Update the moved id's position.
+            self.owned_ids_index.setter(owner).insert(last_id, position);
+        }
+
+        // This also deletes the contents at the last position of the array.
+        owned_ids.pop();
+        self.owned_ids_index.setter(owner).delete(id);
+    }
+
+    /// Adds `holder` to the holders of `id`, if not already there.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `holder` - Account holding tokens of type `id`.
+    pub fn _add_holder_to_id_enumeration(&mut self, id: U256, holder: Address) {
+        if !self.holders_index.get(id).get(holder).is_zero() {
+            return;
+        }
+
+        let mut holders = self.holders.setter(id);
+        holders.push(holder);
+        let position = U256::from(holders.len());
+        self.holders_index.setter(id).insert(holder, position);
+    }
+
+    /// Removes `holder` from the holders of `id`, if there.
+    ///
+    /// This has O(1) time complexity, but alters the order of the holders of
+    /// `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `holder` - Account that no longer holds tokens of type `id`.
+    pub fn _remove_holder_from_id_enumeration(
+        &mut self,
+        id: U256,
+        holder: Address,
+    ) {
+        let position = self.holders_index.get(id).get(holder);
+        if position.is_zero() {
+            return;
+        }
+
+        // Swap and pop, as in [`Self::_remove_id_from_owner_enumeration`].
+        let mut holders = self.holders.setter(id);
+        let index = usize::try_from(position - U256::ONE)
+            .expect("position should fit in `usize`");
+        let last_index = holders.len() - 1;
+
+        if index != last_index {
+            let last_holder = holders
+                .get(last_index)
+                .expect("last holder should be in bounds");
+
+            holders
+                .setter(index)
+                .expect("holder to delete should be in bounds")
+                .set(last_holder);
+            self.holders_index.setter(id).insert(last_holder, position);
+        }
 
-        self.all_ids.get(idx_usize).unwrap_or(U256::ZERO)
+        holders.pop();
+        self.holders_index.setter(id).delete(holder);
     }
 }
 
+/// Returns an [`Error::OutOfBoundsIndex`] error for `index`.
+fn out_of_bounds(index: U256) -> Error {
+    Error::OutOfBoundsIndex(ERC6909OutOfBoundsIndex { index })
+}
+
+/// Returns the bounds of the range of at most `limit` items starting at
+/// `offset`, clamped to a list of `len` items.
+fn range_bounds(offset: U256, limit: U256, len: usize) -> (usize, usize) {
+    let start = usize::try_from(offset).unwrap_or(usize::MAX).min(len);
+    let end = usize::try_from(limit)
+        .unwrap_or(usize::MAX)
+        .saturating_add(start)
+        .min(len);
+    (start, end)
+}
 
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::prelude::*;
+
     use super::*;
-    use alloy_primitives::U256;
-    use stylus_sdk::testing::TestVM;
+    use crate::token::erc6909::{
+        erc6909::{Erc6909, Erc6909Update},
+        error,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
 
-    /// Pulls our extension out of storage so we can drive it like a normal Rust struct.
-    fn fresh_ext() -> Erc6909Enumerable {
-        let vm = TestVM::default();
-        // this `.from(&vm)` is generated by #[storage]
-        Erc6909Enumerable::from(&vm)
+    #[storage]
+    struct EnumerableToken {
+        erc6909: Erc6909,
+        enumerable: Erc6909Enumerable,
     }
 
-    #[test]
-    fn enumerable_tracks_unique_ids() {
-        let mut ext = fresh_ext();
-        // simulate: record [10, 20, 10, 30, 0]
-        for &x in &[10u64, 20, 10, 30, 0] {
-            ext._record_id(U256::from(x));
+    #[public]
+    #[implements(
+        IErc6909Enumerable,
+        IErc6909OwnerEnumerable,
+        IErc6909HolderEnumerable
+    )]
+    impl EnumerableToken {}
+
+    #[public]
+    impl IErc6909Enumerable for EnumerableToken {
+        type Error = Error;
+
+        fn total_ids(&self) -> U256 {
+            self.enumerable.total_ids()
+        }
+
+        fn id_by_index(&self, index: U256) -> Result<U256, Error> {
+            self.enumerable.id_by_index(index)
         }
 
-        // duplicates & zero should have been ignored:
-        assert_eq!(ext.total_ids(), U256::from(3u64));
-        assert_eq!(ext.id_by_index(U256::ZERO), U256::from(10u64));
-        assert_eq!(ext.id_by_index(U256::from(1u64)), U256::from(20u64));
-        assert_eq!(ext.id_by_index(U256::from(2u64)), U256::from(30u64));
+        fn ids_range(&self, offset: U256, limit: U256) -> Vec<U256> {
+            self.enumerable.ids_range(offset, limit)
+        }
     }
 
-    #[test]
-    fn id_by_index_out_of_bounds_returns_zero() {
-        let mut ext = fresh_ext();
-        ext._record_id(U256::from(1u64));
-        // we only have one entry, so asking at index 5 is out-of-bounds:
-        assert_eq!(ext.id_by_index(U256::from(5u64)), U256::ZERO);
+    #[public]
+    impl IErc6909OwnerEnumerable for EnumerableToken {
+        type Error = Error;
+
+        fn ids_of_owner(&self, owner: Address) -> Vec<U256> {
+            self.enumerable.ids_of_owner(owner)
+        }
+
+        fn id_of_owner_by_index(
+            &self,
+            owner: Address,
+            index: U256,
+        ) -> Result<U256, Error> {
+            self.enumerable.id_of_owner_by_index(owner, index)
+        }
+
+        fn owner_count(&self, owner: Address) -> U256 {
+            self.enumerable.owner_count(owner)
+        }
     }
-}
 
-// ——————————————————————————————————————————————————————————————————————————
-// motsu-driven integration tests
-// Run the tests: cargo test -p openzeppelin-stylus --features stylus-test
-// ——————————————————————————————————————————————————————————————————————————
-#[cfg(test)]
-mod motsu_tests {
-    use super::*;
-    use alloy_primitives::U256;
-    use stylus_sdk::testing::TestVM;
-    use motsu::prelude::*;
+    #[public]
+    impl IErc6909HolderEnumerable for EnumerableToken {
+        type Error = Error;
+
+        fn holders_of(&self, id: U256) -> Vec<Address> {
+            self.enumerable.holders_of(id)
+        }
+
+        fn holder_by_index(
+            &self,
+            id: U256,
+            index: U256,
+        ) -> Result<Address, Error> {
+            self.enumerable.holder_by_index(id, index)
+        }
+
+        fn holder_count(&self, id: U256) -> U256 {
+            self.enumerable.holder_count(id)
+        }
+
+        fn holders_range(
+            &self,
+            id: U256,
+            offset: U256,
+            limit: U256,
+        ) -> Vec<Address> {
+            self.enumerable.holders_range(id, offset, limit)
+        }
+    }
+
+    unsafe impl TopLevelStorage for EnumerableToken {}
+    unsafe impl TopLevelStorage for Erc6909Enumerable {}
+
+    impl Erc6909Update for EnumerableToken {
+        type Error = error::Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), error::Error> {
+            self.erc6909._update(from, to, id, amount)?;
+            self.enumerable._update(from, to, id, amount, &self.erc6909);
+            Ok(())
+        }
+    }
+
+    #[motsu::test]
+    fn initial_total_is_zero(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+    ) {
+        assert_eq!(U256::ZERO, contract.sender(alice).total_ids());
+    }
+
+    #[motsu::test]
+    fn tracks_unique_ids(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            for id in [10_u64, 20, 10, 30, 0] {
+                contract._record_id(U256::from(id));
+            }
+        });
+
+        assert_eq!(uint!(3_U256), contract.sender(alice).total_ids());
+        assert_eq!(
+            uint!(10_U256),
+            contract
+                .sender(alice)
+                .id_by_index(U256::ZERO)
+                .expect("should be in bounds")
+        );
+        assert_eq!(
+            uint!(20_U256),
+            contract
+                .sender(alice)
+                .id_by_index(uint!(1_U256))
+                .expect("should be in bounds")
+        );
+        assert_eq!(
+            uint!(30_U256),
+            contract
+                .sender(alice)
+                .id_by_index(uint!(2_U256))
+                .expect("should be in bounds")
+        );
+    }
+
+    #[motsu::test]
+    fn update_records_minted_ids_only(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._update(Address::ZERO, alice, uint!(2_U256), U256::ONE)
+                .expect("should mint bypassing the enumerable hook");
+            contract
+                .erc6909
+                ._update(Address::ZERO, bob, uint!(3_U256), U256::ONE)
+                .expect("should mint bypassing the enumerable hook");
+            contract
+                ._mint(alice, uint!(1_U256), U256::ONE)
+                .expect("should mint");
+            contract
+                ._transfer(alice, bob, uint!(2_U256), U256::ONE)
+                .expect("should transfer");
+            contract._burn(bob, uint!(3_U256), U256::ONE).expect("should burn");
+        });
 
-    // Tell Motsu how to snapshot/rollback this pure‐storage type
-    #[cfg_attr(feature = "enumerable", entrypoint)]
-    unsafe impl stylus_sdk::testing::TopLevelStorage for Erc6909Enumerable {}
+        assert_eq!(U256::ONE, contract.sender(alice).total_ids());
+        assert_eq!(
+            uint!(1_U256),
+            contract
+                .sender(alice)
+                .id_by_index(U256::ZERO)
+                .expect("should be in bounds")
+        );
+    }
+
+    #[motsu::test]
+    fn tracks_ids_of_owner(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            for id in [1_u64, 2, 3] {
+                contract
+                    ._mint(alice, U256::from(id), uint!(10_U256))
+                    .expect("should mint");
+            }
+            contract
+                ._mint(alice, TOKEN_ID, uint!(10_U256))
+                .expect("should mint an id Alice already holds");
+        });
+
+        assert_eq!(uint!(3_U256), contract.sender(alice).owner_count(alice));
+        assert_eq!(
+            vec![uint!(1_U256), uint!(2_U256), uint!(3_U256)],
+            contract.sender(alice).ids_of_owner(alice)
+        );
+        assert_eq!(
+            uint!(2_U256),
+            contract
+                .sender(alice)
+                .id_of_owner_by_index(alice, U256::ONE)
+                .expect("should be in bounds")
+        );
+        assert_eq!(U256::ZERO, contract.sender(alice).owner_count(bob));
+    }
+
+    #[motsu::test]
+    fn removes_emptied_ids_with_swap_and_pop(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            for id in [1_u64, 2, 3] {
+                contract
+                    ._mint(alice, U256::from(id), uint!(10_U256))
+                    .expect("should mint");
+            }
+            contract
+                ._transfer(alice, bob, TOKEN_ID, uint!(4_U256))
+                .expect("should transfer part of Alice's tokens");
+        });
+
+        assert_eq!(uint!(3_U256), contract.sender(alice).owner_count(alice));
+        assert_eq!(vec![TOKEN_ID], contract.sender(alice).ids_of_owner(bob));
+
+        contract.init(alice, |contract| {
+            contract
+                ._transfer(alice, bob, TOKEN_ID, uint!(6_U256))
+                .expect("should transfer the rest of Alice's tokens");
+        });
 
-    /// Helper to get a fresh `Contract<Erc6909Enumerable>`
-    fn fresh() -> Contract<Erc6909Enumerable> {
-        Contract::<Erc6909Enumerable>::new(&TestVM::default())
+        // The last id took the place of the removed one.
+        assert_eq!(
+            vec![uint!(3_U256), uint!(2_U256)],
+            contract.sender(alice).ids_of_owner(alice)
+        );
+        assert_eq!(vec![TOKEN_ID], contract.sender(alice).ids_of_owner(bob));
+
+        contract.init(alice, |contract| {
+            contract
+                ._burn(alice, uint!(2_U256), uint!(10_U256))
+                .expect("should burn Alice's tokens");
+            contract
+                ._transfer(bob, bob, TOKEN_ID, uint!(10_U256))
+                .expect("should transfer Bob's tokens to himself");
+        });
+
+        assert_eq!(
+            vec![uint!(3_U256)],
+            contract.sender(alice).ids_of_owner(alice)
+        );
+        assert_eq!(vec![TOKEN_ID], contract.sender(alice).ids_of_owner(bob));
+        assert_eq!(U256::ONE, contract.sender(alice).owner_count(alice));
     }
 
     #[motsu::test]
-    fn initial_total_is_zero(mut c: Contract<Erc6909Enumerable>) {
-        // With no IDs recorded, we expect zero
-        assert_eq!(c.total_ids(), U256::ZERO);
+    fn tracks_holders_of_id(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                ._mint(alice, TOKEN_ID, uint!(10_U256))
+                .expect("should mint");
+            contract
+                ._transfer(alice, bob, TOKEN_ID, uint!(3_U256))
+                .expect("should transfer part of Alice's tokens to Bob");
+            contract
+                ._transfer(alice, charlie, TOKEN_ID, uint!(3_U256))
+                .expect("should transfer part of Alice's tokens to Charlie");
+        });
+
+        assert_eq!(
+            uint!(3_U256),
+            contract.sender(alice).holder_count(TOKEN_ID)
+        );
+        assert_eq!(
+            vec![alice, bob, charlie],
+            contract.sender(alice).holders_of(TOKEN_ID)
+        );
+
+        contract.init(alice, |contract| {
+            contract
+                ._transfer(alice, charlie, TOKEN_ID, uint!(4_U256))
+                .expect("should transfer the rest of Alice's tokens");
+        });
+
+        // The last holder took the place of the removed one.
+        assert_eq!(
+            vec![charlie, bob],
+            contract.sender(alice).holders_of(TOKEN_ID)
+        );
+        assert_eq!(
+            bob,
+            contract
+                .sender(alice)
+                .holder_by_index(TOKEN_ID, U256::ONE)
+                .expect("should be in bounds")
+        );
+        assert_eq!(
+            uint!(2_U256),
+            contract.sender(alice).holder_count(TOKEN_ID)
+        );
+
+        contract.init(alice, |contract| {
+            contract
+                ._burn(bob, TOKEN_ID, uint!(3_U256))
+                .expect("should burn Bob's tokens");
+        });
+
+        assert_eq!(vec![charlie], contract.sender(alice).holders_of(TOKEN_ID));
     }
 
     #[motsu::test]
-    fn record_zero_id_is_ignored(mut c: Contract<Erc6909Enumerable>) {
-        // Recording the zero‐ID must do nothing
-        c._record_id(U256::ZERO);
-        assert_eq!(c.total_ids(), U256::ZERO);
+    fn holders_range_paginates(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.init(alice, |contract| {
+            for holder in [alice, bob, charlie] {
+                contract
+                    ._mint(holder, TOKEN_ID, U256::ONE)
+                    .expect("should mint");
+            }
+        });
+
+        assert_eq!(
+            vec![alice, bob],
+            contract.sender(alice).holders_range(
+                TOKEN_ID,
+                U256::ZERO,
+                uint!(2_U256)
+            )
+        );
+        assert_eq!(
+            vec![charlie],
+            contract.sender(alice).holders_range(
+                TOKEN_ID,
+                uint!(2_U256),
+                uint!(2_U256)
+            )
+        );
+        assert_eq!(
+            vec![bob, charlie],
+            contract.sender(alice).holders_range(
+                TOKEN_ID,
+                U256::ONE,
+                U256::MAX
+            )
+        );
+        assert!(contract
+            .sender(alice)
+            .holders_range(TOKEN_ID, uint!(3_U256), uint!(2_U256))
+            .is_empty());
+        assert!(contract
+            .sender(alice)
+            .holders_range(TOKEN_ID, U256::MAX, U256::MAX)
+            .is_empty());
     }
 
     #[motsu::test]
-    fn tracks_unique_ids(mut c: Contract<Erc6909Enumerable>) {
-        // simulate: record [10, 20, 10, 30, 0]
-        for &x in &[10u64, 20, 10, 30, 0] {
-            c._record_id(U256::from(x));
+    fn out_of_bounds_index_reverts(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                ._mint(alice, uint!(55_U256), U256::ONE)
+                .expect("should mint");
+        });
+
+        for index in [
+            U256::ONE,
+            uint!(5_U256),
+            U256::from(u64::MAX) + U256::ONE,
+            U256::MAX,
+        ] {
+            let err = contract
+                .sender(alice)
+                .id_by_index(index)
+                .expect_err("should not get an id out of bounds");
+            assert!(matches!(
+                err,
+                Error::OutOfBoundsIndex(ERC6909OutOfBoundsIndex { index: i })
+                    if i == index
+            ));
+
+            let err = contract
+                .sender(alice)
+                .id_of_owner_by_index(alice, index)
+                .expect_err("should not get an owned id out of bounds");
+            assert!(matches!(
+                err,
+                Error::OutOfBoundsIndex(ERC6909OutOfBoundsIndex { index: i })
+                    if i == index
+            ));
+
+            let err = contract
+                .sender(alice)
+                .holder_by_index(uint!(55_U256), index)
+                .expect_err("should not get a holder out of bounds");
+            assert!(matches!(
+                err,
+                Error::OutOfBoundsIndex(ERC6909OutOfBoundsIndex { index: i })
+                    if i == index
+            ));
         }
+    }
 
-        // duplicates & zero should have been ignored
-        assert_eq!(c.total_ids(), U256::from(3u64));
-        assert_eq!(c.id_by_index(U256::ZERO),     U256::from(10u64));
-        assert_eq!(c.id_by_index(U256::from(1u64)), U256::from(20u64));
-        assert_eq!(c.id_by_index(U256::from(2u64)), U256::from(30u64));
+    #[motsu::test]
+    fn ids_range_paginates(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            for id in [10_u64, 20, 30] {
+                contract._record_id(U256::from(id));
+            }
+        });
+
+        assert_eq!(
+            vec![uint!(10_U256), uint!(20_U256)],
+            contract.sender(alice).ids_range(U256::ZERO, uint!(2_U256))
+        );
+        assert_eq!(
+            vec![uint!(30_U256)],
+            contract.sender(alice).ids_range(uint!(2_U256), uint!(2_U256))
+        );
+        assert!(contract
+            .sender(alice)
+            .ids_range(U256::MAX, U256::MAX)
+            .is_empty());
     }
 
     #[motsu::test]
-    fn out_of_bounds_index_returns_zero(mut c: Contract<Erc6909Enumerable>) {
-        // Record a single ID
-        c._record_id(U256::from(55u64));
-        // Asking at index 5 (which doesn't exist) must give zero
-        assert_eq!(c.id_by_index(U256::from(5u64)), U256::ZERO);
+    fn ids_are_kept_at_zero_supply_by_default(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract._mint(alice, TOKEN_ID, U256::ONE).expect("should mint");
+            contract._burn(alice, TOKEN_ID, U256::ONE).expect("should burn");
+        });
+
+        assert_eq!(U256::ONE, contract.sender(alice).total_ids());
+    }
+
+    #[motsu::test]
+    fn zero_amount_mint_does_not_record_id(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract._mint(alice, TOKEN_ID, U256::ZERO).expect("should mint");
+        });
+
+        assert_eq!(U256::ZERO, contract.sender(alice).total_ids());
+        assert!(contract.sender(alice).ids_of_owner(alice).is_empty());
+    }
+
+    #[motsu::test]
+    fn records_zero_id(contract: Contract<EnumerableToken>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract._mint(alice, U256::ZERO, U256::ONE).expect("should mint");
+        });
+
+        assert_eq!(U256::ONE, contract.sender(alice).total_ids());
+        assert_eq!(
+            U256::ZERO,
+            contract
+                .sender(alice)
+                .id_by_index(U256::ZERO)
+                .expect("should be in bounds")
+        );
+    }
+
+    #[motsu::test]
+    fn prunes_ids_at_zero_supply(
+        contract: Contract<EnumerableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.enumerable._set_prune_on_zero_supply(true);
+            for id in [1_u64, 2, 3] {
+                contract
+                    ._mint(alice, U256::from(id), uint!(10_U256))
+                    .expect("should mint");
+            }
+            contract
+                ._transfer(alice, bob, TOKEN_ID, uint!(4_U256))
+                .expect("should transfer part of Alice's tokens");
+            contract
+                ._burn(alice, TOKEN_ID, uint!(6_U256))
+                .expect("should burn Alice's tokens");
+        });
+
+        // Bob still holds `TOKEN_ID`.
+        assert_eq!(uint!(3_U256), contract.sender(alice).total_ids());
+
+        contract.init(alice, |contract| {
+            contract
+                ._transfer(alice, bob, uint!(2_U256), uint!(10_U256))
+                .expect("should transfer all of Alice's tokens");
+        });
+
+        // Moving the whole supply to a new holder keeps the id.
+        assert_eq!(
+            vec![TOKEN_ID, uint!(2_U256), uint!(3_U256)],
+            contract.sender(alice).ids_range(U256::ZERO, U256::MAX)
+        );
+
+        contract.init(alice, |contract| {
+            contract
+                ._burn(bob, TOKEN_ID, uint!(4_U256))
+                .expect("should burn Bob's tokens");
+        });
+
+        // The last id took the place of the pruned one.
+        assert_eq!(
+            vec![uint!(3_U256), uint!(2_U256)],
+            contract.sender(alice).ids_range(U256::ZERO, U256::MAX)
+        );
+
+        contract.init(alice, |contract| {
+            contract._mint(bob, TOKEN_ID, U256::ONE).expect("should mint");
+        });
+
+        assert_eq!(
+            vec![uint!(3_U256), uint!(2_U256), TOKEN_ID],
+            contract.sender(alice).ids_range(U256::ZERO, U256::MAX)
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Enumerable as IErc6909Enumerable>::interface_id();
+        let expected: FixedBytes<4> = 0xc58467d3_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual =
+            <Erc6909Enumerable as IErc6909OwnerEnumerable>::interface_id();
+        let expected: FixedBytes<4> = 0x6dd8b85f_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual =
+            <Erc6909Enumerable as IErc6909HolderEnumerable>::interface_id();
+        let expected: FixedBytes<4> = 0xd62704b6_u32.into();
+        assert_eq!(actual, expected);
     }
 }
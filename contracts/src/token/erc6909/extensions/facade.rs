@@ -0,0 +1,775 @@
+//! ERC-20 facades of single ERC-6909 token types.
+//!
+//! An [`Erc20Facade`] is a companion contract, deployed once per token id,
+//! that exposes the balances of that id through [`IErc20`], so integrations
+//! that only understand ERC-20 can hold and move them. The facade keeps no
+//! balances of its own and forwards every call to its parent ERC-6909
+//! contract.
+//!
+//! The parent registers its facades with [`Erc6909Facades::_set_facade`] and
+//! implements [`IErc6909FacadeHost`] in terms of [`Erc6909FacadeHosting`].
+//! Only the registered facade of an id may move balances or set allowances of
+//! that id on behalf of the account calling the facade, and does so through
+//! the parent's [`Erc6909Update`] hook, so the ledger stays shared. Transfers
+//! and approvals made through the facade emit the ERC-20 events on the facade
+//! and the ERC-6909 events on the parent.
+//!
+//! NOTE: Transfers made directly on the parent emit the ERC-6909 events only,
+//! so indexers tracking the facade through its ERC-20 events alone may miss
+//! them.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use crate::{
+    token::{
+        erc20::{self, IErc20},
+        erc6909::{
+            self,
+            erc6909::Erc6909Update,
+            error,
+            interface::{Erc6909FacadeHostInterface, Erc6909Interface},
+        },
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `facade` becomes the ERC-20 facade of token type `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `facade` - Address of the facade, or [`Address::ZERO`] if `id`
+        ///   no longer has a facade.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event FacadeSet(uint256 indexed id, address indexed facade);
+
+        /// Indicates that `facade` is not the ERC-20 facade of token type
+        /// `id`.
+        ///
+        /// * `facade` - Account calling a facade entry point.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedFacade(address facade, uint256 id);
+
+        /// Indicates that a call of a facade to its `parent` failed.
+        ///
+        /// * `parent` - Address of the parent ERC-6909 contract.
+        /// * `id` - Token id of the facade.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909FacadeFailedOperation(address parent, uint256 id);
+    }
+}
+
+/// An [`Erc6909FacadeHosting`] or [`Erc20Facade`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(error::ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(error::ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(error::ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(error::ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+    /// The caller is not the facade of the token type.
+    UnauthorizedFacade(ERC6909UnauthorizedFacade),
+    /// A call of a facade to its parent failed.
+    FacadeFailedOperation(ERC6909FacadeFailedOperation),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Facades`] contract.
+#[storage]
+pub struct Erc6909Facades {
+    /// Mapping from token id to the address of its ERC-20 facade.
+    pub(crate) facades: StorageMap<U256, StorageAddress>,
+}
+
+impl Erc6909Facades {
+    /// See [`IErc6909FacadeHost::facade_of`].
+    #[must_use]
+    pub fn facade_of(&self, id: U256) -> Address {
+        self.facades.get(id)
+    }
+
+    /// Sets `facade` as the ERC-20 facade of token type `id`, replacing the
+    /// previous one, if any.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `facade` - Address of the facade, or [`Address::ZERO`] to remove the
+    ///   facade of `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`FacadeSet`].
+    pub fn _set_facade(&mut self, id: U256, facade: Address) {
+        self.facades.setter(id).set(facade);
+        evm::log(FacadeSet { id, facade });
+    }
+}
+
+/// Interface of an ERC-6909 token trusting one ERC-20 facade per token type.
+#[interface_id]
+pub trait IErc6909FacadeHost {
+    /// The error type associated to the trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the address of the ERC-20 facade of token type `id`, or
+    /// [`Address::ZERO`] if it has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    #[must_use]
+    fn facade_of(&self, id: U256) -> Address;
+
+    /// Transfers an `amount` of tokens of type `id` from `from` to `to`, on
+    /// behalf of `from` calling the facade of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account calling the facade.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedFacade`] - If the caller is not the facade of
+    ///   `id`.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn facade_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error>;
+
+    /// Transfers an `amount` of tokens of type `id` from `from` to `to`, on
+    /// behalf of `spender` calling the facade of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account calling the facade.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedFacade`] - If the caller is not the facade of
+    ///   `id`.
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `from`, nor
+    ///   an operator of `from`, nor allowed to spend `amount`.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn facade_transfer_from(
+        &mut self,
+        spender: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error>;
+
+    /// Sets `amount` as the allowance of `spender` over the tokens of type
+    /// `id` of `owner`, on behalf of `owner` calling the facade of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account calling the facade.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` may spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedFacade`] - If the caller is not the facade of
+    ///   `id`.
+    /// * [`Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Approval`].
+    fn facade_approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error>;
+}
+
+/// ERC-6909 token moving balances on behalf of its ERC-20 facades.
+///
+/// Layered over the [`Erc6909Update`] hook, so facade transfers go through
+/// every other extension of the contract.
+pub trait Erc6909FacadeHosting: Erc6909Update
+where
+    Self::Error: From<Error>,
+{
+    /// Returns the [`Erc6909Facades`] contract holding the facades.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn facades(&self) -> &Erc6909Facades;
+
+    /// Checks that `caller` is the facade of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `caller` - Account calling a facade entry point.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedFacade`] - If `caller` is not the facade of `id`.
+    fn _check_facade(&self, caller: Address, id: U256) -> Result<(), Error> {
+        let facade = self.facades().facade_of(id);
+        if facade.is_zero() || facade != caller {
+            return Err(Error::UnauthorizedFacade(ERC6909UnauthorizedFacade {
+                facade: caller,
+                id,
+            }));
+        }
+        Ok(())
+    }
+
+    /// See [`IErc6909FacadeHost::facade_transfer`].
+    #[allow(clippy::missing_errors_doc)]
+    fn _facade_transfer(
+        &mut self,
+        caller: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._check_facade(caller, id)?;
+        self._transfer(from, to, id, amount)
+    }
+
+    /// See [`IErc6909FacadeHost::facade_transfer_from`].
+    #[allow(clippy::missing_errors_doc)]
+    fn _facade_transfer_from(
+        &mut self,
+        caller: Address,
+        spender: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._check_facade(caller, id)?;
+        self._transfer_from(spender, from, to, id, amount)
+    }
+
+    /// See [`IErc6909FacadeHost::facade_approve`].
+    #[allow(clippy::missing_errors_doc)]
+    fn _facade_approve(
+        &mut self,
+        caller: Address,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._check_facade(caller, id)?;
+        self.erc6909_mut()._approve(owner, spender, id, amount)?;
+        Ok(())
+    }
+}
+
+/// State of an [`Erc20Facade`] contract.
+#[storage]
+pub struct Erc20Facade {
+    /// Address of the parent ERC-6909 contract.
+    pub(crate) parent: StorageAddress,
+    /// Token id exposed by the facade.
+    pub(crate) id: StorageU256,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc20Facade {}
+
+#[public]
+#[implements(IErc20<Error = Error>, IErc165)]
+impl Erc20Facade {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `parent` - Address of the parent ERC-6909 contract.
+    /// * `id` - Token id exposed by the facade.
+    #[constructor]
+    pub fn constructor(&mut self, parent: Address, id: U256) {
+        self.parent.set(parent);
+        self.id.set(id);
+    }
+
+    /// Returns the address of the parent ERC-6909 contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn parent(&self) -> Address {
+        self.parent.get()
+    }
+
+    /// Returns the token id exposed by the facade.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn id(&self) -> U256 {
+        self.id.get()
+    }
+}
+
+#[public]
+impl IErc20 for Erc20Facade {
+    type Error = Error;
+
+    fn total_supply(&self) -> U256 {
+        Erc6909Interface::new(self.parent())
+            .total_supply(self, self.id())
+            .expect("parent should return `totalSupply`")
+    }
+
+    fn balance_of(&self, account: Address) -> U256 {
+        Erc6909Interface::new(self.parent())
+            .balance_of(self, account, self.id())
+            .expect("parent should return `balanceOf`")
+    }
+
+    fn transfer(&mut self, to: Address, value: U256) -> Result<bool, Error> {
+        let from = msg::sender();
+        let (parent, id) = (self.parent(), self.id());
+        Erc6909FacadeHostInterface::new(parent)
+            .facade_transfer(Call::new_in(self), from, to, id, value)
+            .map_err(|_| ERC6909FacadeFailedOperation { parent, id })?;
+        evm::log(erc20::Transfer { from, to, value });
+        Ok(true)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        Erc6909Interface::new(self.parent())
+            .allowance(self, owner, spender, self.id())
+            .expect("parent should return `allowance`")
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        let owner = msg::sender();
+        let (parent, id) = (self.parent(), self.id());
+        Erc6909FacadeHostInterface::new(parent)
+            .facade_approve(Call::new_in(self), owner, spender, id, value)
+            .map_err(|_| ERC6909FacadeFailedOperation { parent, id })?;
+        evm::log(erc20::Approval { owner, spender, value });
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        let spender = msg::sender();
+        let (parent, id) = (self.parent(), self.id());
+        Erc6909FacadeHostInterface::new(parent)
+            .facade_transfer_from(
+                Call::new_in(self),
+                spender,
+                from,
+                to,
+                id,
+                value,
+            )
+            .map_err(|_| ERC6909FacadeFailedOperation { parent, id })?;
+        evm::log(erc20::Transfer { from, to, value });
+        Ok(true)
+    }
+}
+
+#[public]
+impl IErc165 for Erc20Facade {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc20>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::uint;
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::{
+        extensions::Erc6909Supply, Approval, IErc6909, IErc6909Supply, Transfer,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+
+    #[storage]
+    struct HostToken {
+        supply: Erc6909Supply,
+        facades: Erc6909Facades,
+    }
+
+    #[public]
+    #[implements(IErc6909FacadeHost<Error = Error>)]
+    impl HostToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.supply.balance_of(owner, id)
+        }
+
+        fn allowance(
+            &self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+        ) -> U256 {
+            self.supply.allowance(owner, spender, id)
+        }
+
+        fn total_supply(&self, id: U256) -> U256 {
+            self.supply.total_supply(id)
+        }
+
+        fn set_facade(&mut self, id: U256, facade: Address) {
+            self.facades._set_facade(id, facade);
+        }
+    }
+
+    #[public]
+    impl IErc6909FacadeHost for HostToken {
+        type Error = Error;
+
+        fn facade_of(&self, id: U256) -> Address {
+            self.facades.facade_of(id)
+        }
+
+        fn facade_transfer(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<bool, Error> {
+            self._facade_transfer(msg::sender(), from, to, id, amount)?;
+            Ok(true)
+        }
+
+        fn facade_transfer_from(
+            &mut self,
+            spender: Address,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<bool, Error> {
+            self._facade_transfer_from(
+                msg::sender(),
+                spender,
+                from,
+                to,
+                id,
+                amount,
+            )?;
+            Ok(true)
+        }
+
+        fn facade_approve(
+            &mut self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<bool, Error> {
+            self._facade_approve(msg::sender(), owner, spender, id, amount)?;
+            Ok(true)
+        }
+    }
+
+    unsafe impl TopLevelStorage for HostToken {}
+
+    impl Erc6909Update for HostToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &erc6909::Erc6909 {
+            &self.supply.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut erc6909::Erc6909 {
+            &mut self.supply.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.supply._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909FacadeHosting for HostToken {
+        fn facades(&self) -> &Erc6909Facades {
+            &self.facades
+        }
+    }
+
+    fn deploy(
+        host: &Contract<HostToken>,
+        facade: &Contract<Erc20Facade>,
+        owner: Address,
+    ) {
+        host.init(owner, |host| {
+            host._mint(owner, TOKEN_ID, AMOUNT).expect("should mint");
+            host.facades._set_facade(TOKEN_ID, facade.address());
+        });
+        facade.init(owner, |facade| {
+            facade.constructor(host.address(), TOKEN_ID);
+        });
+    }
+
+    #[motsu::test]
+    fn facade_reads_parent_ledger(
+        host: Contract<HostToken>,
+        facade: Contract<Erc20Facade>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&host, &facade, alice);
+
+        assert_eq!(AMOUNT, facade.sender(alice).balance_of(alice));
+        assert_eq!(U256::ZERO, facade.sender(alice).balance_of(bob));
+        assert_eq!(AMOUNT, facade.sender(alice).total_supply());
+        assert_eq!(facade.address(), host.sender(alice).facade_of(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn facade_transfer_updates_parent_ledger(
+        host: Contract<HostToken>,
+        facade: Contract<Erc20Facade>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&host, &facade, alice);
+        let value = uint!(30_U256);
+
+        let result = facade
+            .sender(alice)
+            .transfer(bob, value)
+            .motsu_expect("should transfer");
+        assert!(result);
+
+        facade.assert_emitted(&erc20::Transfer { from: alice, to: bob, value });
+        host.assert_emitted(&Transfer {
+            caller: facade.address(),
+            sender: alice,
+            receiver: bob,
+            id: TOKEN_ID,
+            amount: value,
+        });
+        assert_eq!(value, host.sender(alice).balance_of(bob, TOKEN_ID));
+        assert_eq!(
+            AMOUNT - value,
+            host.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn facade_approve_and_transfer_from(
+        host: Contract<HostToken>,
+        facade: Contract<Erc20Facade>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&host, &facade, alice);
+        let value = uint!(40_U256);
+
+        facade.sender(alice).approve(bob, value).motsu_expect("should approve");
+        facade.assert_emitted(&erc20::Approval {
+            owner: alice,
+            spender: bob,
+            value,
+        });
+        host.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: value,
+        });
+        assert_eq!(value, facade.sender(alice).allowance(alice, bob));
+
+        facade
+            .sender(bob)
+            .transfer_from(alice, bob, value)
+            .motsu_expect("should transfer");
+        assert_eq!(value, host.sender(alice).balance_of(bob, TOKEN_ID));
+        assert_eq!(U256::ZERO, facade.sender(alice).allowance(alice, bob));
+
+        let err = facade
+            .sender(bob)
+            .transfer_from(alice, bob, value)
+            .motsu_expect_err("should return Error::FacadeFailedOperation");
+        assert!(matches!(
+            err,
+            Error::FacadeFailedOperation(ERC6909FacadeFailedOperation {
+                parent,
+                id,
+            }) if parent == host.address() && id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn only_facade_may_call_entry_points(
+        host: Contract<HostToken>,
+        facade: Contract<Erc20Facade>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&host, &facade, alice);
+
+        let err = host
+            .sender(bob)
+            .facade_transfer(alice, bob, TOKEN_ID, AMOUNT)
+            .motsu_expect_err("should return Error::UnauthorizedFacade");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedFacade(ERC6909UnauthorizedFacade { facade, id })
+                if facade == bob && id == TOKEN_ID
+        ));
+
+        let other_id = uint!(2_U256);
+        let err = host
+            .sender(facade.address())
+            .facade_approve(alice, bob, other_id, AMOUNT)
+            .motsu_expect_err("should return Error::UnauthorizedFacade");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedFacade(ERC6909UnauthorizedFacade {
+                facade: caller,
+                id,
+            }) if caller == facade.address() && id == other_id
+        ));
+        assert_eq!(AMOUNT, host.sender(alice).balance_of(alice, TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn set_facade_emits_event(
+        host: Contract<HostToken>,
+        facade: Contract<Erc20Facade>,
+        alice: Address,
+    ) {
+        host.sender(alice).set_facade(TOKEN_ID, facade.address());
+        host.assert_emitted(&FacadeSet {
+            id: TOKEN_ID,
+            facade: facade.address(),
+        });
+
+        host.sender(alice).set_facade(TOKEN_ID, Address::ZERO);
+        assert_eq!(Address::ZERO, host.sender(alice).facade_of(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <HostToken as IErc6909FacadeHost>::interface_id();
+        let expected: FixedBytes<4> = 0x7cc3d83a_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
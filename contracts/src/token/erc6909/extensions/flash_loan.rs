@@ -0,0 +1,860 @@
+//! Extension of ERC-6909 that adds flash loans of every token type, in the
+//! style of [ERC-3156].
+//!
+//! A flash loan of `amount` tokens of type `id` is either minted for the
+//! duration of the loan, or lent out of the balance of `id` held by the
+//! contract itself, as decided per id by
+//! [`Erc6909FlashLending::_is_flash_mintable`]. The receiver gets the tokens,
+//! is called back through [`IERC6909FlashBorrower::on_flash_loan`], and must
+//! have approved the contract to take back `amount` plus the fee by the time
+//! the callback returns, or the whole loan reverts.
+//!
+//! By default each token type has the flat fee set with
+//! [`Erc6909FlashLoans::_set_flash_fee`], paid to the receiver set with
+//! [`Erc6909FlashLoans::_set_flash_fee_receiver`]. Both can be changed by
+//! overriding [`Erc6909FlashLending::_flash_fee`] and
+//! [`Erc6909FlashLending::_flash_fee_receiver`].
+//!
+//! NOTE: A borrower approving the repayment from within its callback reenters
+//! the token, which Stylus only allows when the crate is built with the
+//! `reentrant` feature, as in `examples/erc20-flash-mint`. Without it,
+//! borrowers must approve the contract, or make it an operator, before
+//! requesting the loan.
+//!
+//! [ERC-3156]: https://eips.ethereum.org/EIPS/eip-3156
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes,
+    call::{Call, MethodError},
+    contract, evm,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use crate::token::{
+    erc20::extensions::flash_mint::{
+        ERC3156ExceededMaxLoan, ERC3156InvalidReceiver,
+    },
+    erc6909::{
+        self,
+        erc6909::Erc6909Update,
+        error,
+        traits::{IErc6909, IErc6909Supply},
+    },
+};
+
+/// The expected value returned from [`IERC6909FlashBorrower::on_flash_loan`].
+pub const BORROWER_CALLBACK_VALUE: [u8; 32] = keccak_const::Keccak256::new()
+    .update("ERC6909FlashBorrower.onFlashLoan".as_bytes())
+    .finalize();
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the flash fee of token type `id` is set to `fee`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `fee` - Flat fee of a flash loan of `id`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event FlashFeeSet(uint256 indexed id, uint256 fee);
+
+        /// Emitted when the receiver of flash fees is set to `receiver`.
+        ///
+        /// * `receiver` - Account receiving the flash fees, or
+        ///   [`Address::ZERO`] if they are burned or kept by the contract.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event FlashFeeReceiverSet(address indexed receiver);
+    }
+}
+
+/// An [`Erc6909FlashLending`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicate an error related to the loan value exceeding the maximum.
+    ExceededMaxLoan(ERC3156ExceededMaxLoan),
+    /// Indicate that the receiver of a flash loan is not a valid
+    /// [`IERC6909FlashBorrower::on_flash_loan`] implementer.
+    ERC3156InvalidReceiver(ERC3156InvalidReceiver),
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(error::ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(error::ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(error::ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(error::ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+pub use borrower::IERC6909FlashBorrower;
+mod borrower {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of a borrower of ERC-6909 flash loans, following the
+        /// ERC-3156 FlashBorrower with the token id of the loan.
+        interface IERC6909FlashBorrower {
+            /// Receives a flash loan.
+            ///
+            /// To indicate successful handling of the flash loan, this function should return
+            /// the `keccak256` hash of "ERC6909FlashBorrower.onFlashLoan".
+            ///
+            /// # Arguments
+            ///
+            /// * `initiator` - The initiator of the flash loan.
+            /// * `token` - The ERC-6909 contract lending the tokens.
+            /// * `id` - The token id of the loan.
+            /// * `amount` - The amount of tokens lent.
+            /// * `fee` - The additional amount of tokens to repay.
+            /// * `data` - Arbitrary data structure, intended to contain user-defined parameters.
+            #[allow(missing_docs)]
+            function onFlashLoan(
+                address initiator,
+                address token,
+                uint256 id,
+                uint256 amount,
+                uint256 fee,
+                bytes calldata data
+            ) external returns (bytes32);
+        }
+    }
+}
+
+/// State of an [`Erc6909FlashLoans`] contract.
+#[storage]
+pub struct Erc6909FlashLoans {
+    /// Mapping from token id to the flat fee of its flash loans.
+    pub(crate) flash_fees: StorageMap<U256, StorageU256>,
+    /// Receiver of the flash fees.
+    pub(crate) flash_fee_receiver: StorageAddress,
+}
+
+impl Erc6909FlashLoans {
+    /// Returns the flat fee of a flash loan of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    #[must_use]
+    pub fn flash_fee(&self, id: U256) -> U256 {
+        self.flash_fees.get(id)
+    }
+
+    /// Returns the receiver of the flash fees, or [`Address::ZERO`] if they
+    /// are burned or kept by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn flash_fee_receiver(&self) -> Address {
+        self.flash_fee_receiver.get()
+    }
+
+    /// Sets `fee` as the flat fee of a flash loan of token type `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `fee` - Flat fee of a flash loan of `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`FlashFeeSet`].
+    pub fn _set_flash_fee(&mut self, id: U256, fee: U256) {
+        self.flash_fees.setter(id).set(fee);
+        evm::log(FlashFeeSet { id, fee });
+    }
+
+    /// Sets `receiver` as the receiver of the flash fees.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account receiving the flash fees, or [`Address::ZERO`] to
+    ///   burn them, or keep them in the contract when lending its balance.
+    ///
+    /// # Events
+    ///
+    /// * [`FlashFeeReceiverSet`].
+    pub fn _set_flash_fee_receiver(&mut self, receiver: Address) {
+        self.flash_fee_receiver.set(receiver);
+        evm::log(FlashFeeReceiverSet { receiver });
+    }
+}
+
+/// Interface of an ERC-6909 flash lender, following the ERC-3156 Flash Lender
+/// with token ids in place of token addresses.
+#[interface_id]
+pub trait IErc6909FlashLender {
+    /// The error type associated to this trait implementation.
+    type Error: Into<Vec<u8>>;
+
+    /// Returns the maximum amount of tokens of type `id` available for loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    #[must_use]
+    fn max_flash_loan(&self, id: U256) -> U256;
+
+    /// Returns the fee applied when flash loaning an `amount` of tokens of
+    /// type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `amount` - The amount of tokens to be loaned.
+    #[must_use]
+    fn flash_fee(&self, id: U256, amount: U256) -> U256;
+
+    /// Performs a flash loan.
+    ///
+    /// The tokens are sent to the `receiver`, who is required to implement
+    /// the [`IERC6909FlashBorrower`] interface. By the end of the flash loan,
+    /// the receiver is expected to own `amount` plus the fee and have them
+    /// approved back to the token contract itself.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - The receiver of the flash loan. Should implement the
+    ///   [`IERC6909FlashBorrower::on_flash_loan`] interface.
+    /// * `id` - Token id as a number.
+    /// * `amount` - The amount of tokens to be loaned.
+    /// * `data` - Arbitrary data that is passed to the receiver.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxLoan`] - If `amount` is greater than the value
+    ///   returned by [`IErc6909FlashLender::max_flash_loan`].
+    /// * [`Error::ERC3156InvalidReceiver`] - If `receiver` is not a contract,
+    ///   the callback fails, or it does not return [`BORROWER_CALLBACK_VALUE`].
+    /// * [`Error::InsufficientAllowance`] - If the receiver hasn't approved
+    ///   `amount` plus the fee back to the contract.
+    /// * [`Error::InsufficientBalance`] - If the receiver doesn't hold `amount`
+    ///   plus the fee at the end of the loan.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    /// * [`crate::token::erc6909::Approval`].
+    fn flash_loan(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, Self::Error>;
+}
+
+/// ERC-6909 token lending every token type through flash loans.
+///
+/// Layered over the [`Erc6909Update`] hook, so the loans go through every
+/// other extension of the contract.
+pub trait Erc6909FlashLending:
+    Erc6909Update + IErc6909Supply + TopLevelStorage + Sized
+where
+    Self::Error: From<Error>,
+{
+    /// Returns the [`Erc6909FlashLoans`] contract holding the fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn flash_loans(&self) -> &Erc6909FlashLoans;
+
+    /// Returns whether flash loans of token type `id` are minted, rather
+    /// than lent out of the balance held by the contract. Every id is minted
+    /// by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn _is_flash_mintable(&self, _id: U256) -> bool {
+        true
+    }
+
+    /// See [`IErc6909FlashLender::max_flash_loan`].
+    ///
+    /// Minted loans are bounded by the supply of all ids, since minting any
+    /// id also increases [`IErc6909Supply::total_supply_all`].
+    ///
+    /// NOTE: This function does not consider any form of supply cap, so in
+    /// case it's used with [`crate::token::erc6909::Erc6909Capped`], make
+    /// sure to override it to integrate the cap instead of [`U256::MAX`].
+    fn _max_flash_loan(&self, id: U256) -> U256 {
+        if self._is_flash_mintable(id) {
+            U256::MAX - self.total_supply_all()
+        } else {
+            self.erc6909().balance_of(contract::address(), id)
+        }
+    }
+
+    /// See [`IErc6909FlashLender::flash_fee`].
+    fn _flash_fee(&self, id: U256, _amount: U256) -> U256 {
+        self.flash_loans().flash_fee(id)
+    }
+
+    /// Returns the receiver of the fees of flash loans of token type `id`,
+    /// or [`Address::ZERO`] to burn them, or keep them in the contract when
+    /// lending its balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `_id` - Token id as a number.
+    fn _flash_fee_receiver(&self, _id: U256) -> Address {
+        self.flash_loans().flash_fee_receiver()
+    }
+
+    // This function can reenter, but it doesn't pose a risk because it always
+    // preserves the property that the amount minted or lent at the beginning
+    // is always recovered at the end, or else the entire function will revert.
+    /// See [`IErc6909FlashLender::flash_loan`].
+    ///
+    /// # Panics
+    ///
+    /// * If the sum of `amount` and the fee exceeds [`U256::MAX`].
+    #[allow(clippy::missing_errors_doc)]
+    fn _flash_loan(
+        &mut self,
+        initiator: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        data: &Bytes,
+    ) -> Result<(), Self::Error> {
+        let max_loan = self._max_flash_loan(id);
+        if amount > max_loan {
+            return Err(Error::ExceededMaxLoan(ERC3156ExceededMaxLoan {
+                max_loan,
+            })
+            .into());
+        }
+
+        let fee = self._flash_fee(id, amount);
+        if !Address::has_code(&receiver) {
+            return Err(Error::ERC3156InvalidReceiver(
+                ERC3156InvalidReceiver { receiver },
+            )
+            .into());
+        }
+
+        let contract_address = contract::address();
+        let mintable = self._is_flash_mintable(id);
+        if mintable {
+            self._mint(receiver, id, amount)?;
+        } else {
+            self._transfer(contract_address, receiver, id, amount)?;
+        }
+
+        let loan_return = IERC6909FlashBorrower::new(receiver)
+            .on_flash_loan(
+                Call::new_in(self),
+                initiator,
+                contract_address,
+                id,
+                amount,
+                fee,
+                data.to_vec().into(),
+            )
+            .map_err(|_| {
+                Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver {
+                    receiver,
+                })
+            })?;
+        if loan_return != BORROWER_CALLBACK_VALUE {
+            return Err(Error::ERC3156InvalidReceiver(
+                ERC3156InvalidReceiver { receiver },
+            )
+            .into());
+        }
+
+        let repayment = amount
+            .checked_add(fee)
+            .expect("repayment should not exceed `U256::MAX`");
+        self.erc6909_mut()._authorize_transfer(
+            receiver,
+            contract_address,
+            id,
+            repayment,
+        )?;
+
+        let fee_receiver = self._flash_fee_receiver(id);
+        if mintable {
+            if fee.is_zero() || fee_receiver.is_zero() {
+                self._burn(receiver, id, repayment)?;
+            } else {
+                self._burn(receiver, id, amount)?;
+                self._transfer(receiver, fee_receiver, id, fee)?;
+            }
+        } else if fee.is_zero() || fee_receiver.is_zero() {
+            self._transfer(receiver, contract_address, id, repayment)?;
+        } else {
+            self._transfer(receiver, contract_address, id, amount)?;
+            self._transfer(receiver, fee_receiver, id, fee)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{uint, FixedBytes};
+    use motsu::prelude::*;
+    use stylus_sdk::{msg, storage::StorageBool};
+
+    use super::*;
+    use crate::token::erc6909::{extensions::Erc6909Supply, Erc6909};
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(1000_U256);
+    const FEE: U256 = uint!(10_U256);
+
+    #[storage]
+    struct FlashToken {
+        supply: Erc6909Supply,
+        flash_loans: Erc6909FlashLoans,
+        lend_held_balance: StorageBool,
+    }
+
+    #[public]
+    #[implements(IErc6909FlashLender<Error = Error>)]
+    impl FlashToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.supply.balance_of(owner, id)
+        }
+    }
+
+    #[public]
+    impl IErc6909FlashLender for FlashToken {
+        type Error = Error;
+
+        fn max_flash_loan(&self, id: U256) -> U256 {
+            self._max_flash_loan(id)
+        }
+
+        fn flash_fee(&self, id: U256, amount: U256) -> U256 {
+            self._flash_fee(id, amount)
+        }
+
+        fn flash_loan(
+            &mut self,
+            receiver: Address,
+            id: U256,
+            amount: U256,
+            data: Bytes,
+        ) -> Result<bool, Error> {
+            self._flash_loan(msg::sender(), receiver, id, amount, &data)?;
+            Ok(true)
+        }
+    }
+
+    unsafe impl TopLevelStorage for FlashToken {}
+
+    impl IErc6909Supply for FlashToken {
+        fn total_supply(&self, id: U256) -> U256 {
+            self.supply.total_supply(id)
+        }
+
+        fn total_supply_all(&self) -> U256 {
+            self.supply.total_supply_all()
+        }
+
+        fn holders_count(&self, id: U256) -> U256 {
+            self.supply.holders_count(id)
+        }
+    }
+
+    impl Erc6909Update for FlashToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.supply.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.supply.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.supply._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909FlashLending for FlashToken {
+        fn flash_loans(&self) -> &Erc6909FlashLoans {
+            &self.flash_loans
+        }
+
+        fn _is_flash_mintable(&self, _id: U256) -> bool {
+            !self.lend_held_balance.get()
+        }
+    }
+
+    #[storage]
+    struct Borrower {
+        reject: StorageBool,
+        received: StorageU256,
+    }
+
+    #[public]
+    impl Borrower {
+        #[selector(name = "onFlashLoan")]
+        fn on_flash_loan(
+            &mut self,
+            _initiator: Address,
+            token: Address,
+            id: U256,
+            amount: U256,
+            _fee: U256,
+            _data: Bytes,
+        ) -> FixedBytes<32> {
+            assert_eq!(msg::sender(), token);
+            assert_eq!(id, TOKEN_ID);
+            self.received.set(amount);
+            if self.reject.get() {
+                FixedBytes::ZERO
+            } else {
+                BORROWER_CALLBACK_VALUE.into()
+            }
+        }
+    }
+
+    unsafe impl TopLevelStorage for Borrower {}
+
+    /// Approves the repayment up front, since the borrower can't reenter the
+    /// token from its callback in these tests.
+    fn approve_repayment(
+        contract: &Contract<FlashToken>,
+        borrower: &Contract<Borrower>,
+        owner: Address,
+        fee: U256,
+    ) {
+        let borrower = borrower.address();
+        contract.init(owner, |contract| {
+            contract
+                .supply
+                .erc6909
+                ._approve(borrower, contract::address(), TOKEN_ID, AMOUNT + fee)
+                .expect("should approve");
+        });
+    }
+
+    #[motsu::test]
+    fn max_flash_loan_depends_on_mode(
+        contract: Contract<FlashToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract._mint(alice, TOKEN_ID, AMOUNT).expect("should mint");
+        });
+        assert_eq!(
+            U256::MAX - AMOUNT,
+            contract.sender(alice).max_flash_loan(TOKEN_ID)
+        );
+
+        contract.init(alice, |contract| {
+            contract.lend_held_balance.set(true);
+            contract
+                ._transfer(alice, contract::address(), TOKEN_ID, FEE)
+                .expect("should transfer");
+        });
+        assert_eq!(FEE, contract.sender(alice).max_flash_loan(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn flash_mint_of_max_loan_counts_other_ids(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        let other_id = uint!(2_U256);
+        contract.init(alice, |contract| {
+            contract._mint(alice, other_id, AMOUNT).expect("should mint");
+        });
+
+        let max_loan = contract.sender(alice).max_flash_loan(TOKEN_ID);
+        assert_eq!(U256::MAX - AMOUNT, max_loan);
+
+        let borrower_address = borrower.address();
+        contract.init(alice, |contract| {
+            contract
+                .supply
+                .erc6909
+                ._approve(
+                    borrower_address,
+                    contract::address(),
+                    TOKEN_ID,
+                    max_loan,
+                )
+                .expect("should approve");
+        });
+
+        contract
+            .sender(alice)
+            .flash_loan(borrower_address, TOKEN_ID, max_loan, vec![].into())
+            .motsu_expect("should flash loan");
+
+        assert_eq!(max_loan, borrower.sender(alice).received.get());
+        assert_eq!(AMOUNT, contract.sender(alice).total_supply_all());
+    }
+
+    #[motsu::test]
+    fn flash_fee_is_per_id(contract: Contract<FlashToken>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract.flash_loans._set_flash_fee(TOKEN_ID, FEE);
+        });
+        contract.assert_emitted(&FlashFeeSet { id: TOKEN_ID, fee: FEE });
+
+        assert_eq!(FEE, contract.sender(alice).flash_fee(TOKEN_ID, AMOUNT));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).flash_fee(uint!(2_U256), AMOUNT)
+        );
+    }
+
+    #[motsu::test]
+    fn flash_mint_burns_repayment(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        approve_repayment(&contract, &borrower, alice, U256::ZERO);
+
+        let result = contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect("should flash loan");
+
+        assert!(result);
+        assert_eq!(AMOUNT, borrower.sender(alice).received.get());
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(borrower.address(), TOKEN_ID)
+        );
+        assert_eq!(U256::MAX, contract.sender(alice).max_flash_loan(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn flash_mint_pays_fee_to_receiver(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.flash_loans._set_flash_fee(TOKEN_ID, FEE);
+            contract.flash_loans._set_flash_fee_receiver(bob);
+            contract
+                ._mint(borrower.address(), TOKEN_ID, FEE)
+                .expect("should mint");
+        });
+        approve_repayment(&contract, &borrower, alice, FEE);
+
+        contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect("should flash loan");
+
+        assert_eq!(FEE, contract.sender(alice).balance_of(bob, TOKEN_ID));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(borrower.address(), TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn flash_lend_returns_held_balance(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.lend_held_balance.set(true);
+            contract.flash_loans._set_flash_fee(TOKEN_ID, FEE);
+            contract
+                ._mint(contract::address(), TOKEN_ID, AMOUNT)
+                .expect("should mint");
+            contract
+                ._mint(borrower.address(), TOKEN_ID, FEE)
+                .expect("should mint");
+        });
+        approve_repayment(&contract, &borrower, alice, FEE);
+
+        contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect("should flash loan");
+
+        assert_eq!(AMOUNT, borrower.sender(alice).received.get());
+        assert_eq!(
+            AMOUNT + FEE,
+            contract.sender(alice).balance_of(contract.address(), TOKEN_ID)
+        );
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(borrower.address(), TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn flash_loan_reverts_when_exceeded_max_loan(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.lend_held_balance.set(true);
+        });
+
+        let err = contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect_err("should return Error::ExceededMaxLoan");
+
+        assert!(matches!(
+            err,
+            Error::ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan })
+                if max_loan.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn flash_loan_reverts_when_invalid_receiver(
+        contract: Contract<FlashToken>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .flash_loan(alice, TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect_err("should return Error::ERC3156InvalidReceiver");
+
+        assert!(matches!(
+            err,
+            Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver { receiver })
+                if receiver == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn flash_loan_reverts_when_callback_rejects(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        borrower.init(alice, |borrower| borrower.reject.set(true));
+        approve_repayment(&contract, &borrower, alice, U256::ZERO);
+
+        let err = contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect_err("should return Error::ERC3156InvalidReceiver");
+
+        assert!(matches!(
+            err,
+            Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver { receiver })
+                if receiver == borrower.address()
+        ));
+    }
+
+    #[motsu::test]
+    fn flash_loan_reverts_without_repayment_approval(
+        contract: Contract<FlashToken>,
+        borrower: Contract<Borrower>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .flash_loan(borrower.address(), TOKEN_ID, AMOUNT, vec![].into())
+            .motsu_expect_err("should return Error::InsufficientAllowance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(
+                error::ERC6909InsufficientAllowance { spender, needed, .. }
+            ) if spender == contract.address() && needed == AMOUNT
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <FlashToken as IErc6909FlashLender>::interface_id();
+        let expected: FixedBytes<4> = 0xb5d048fc_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
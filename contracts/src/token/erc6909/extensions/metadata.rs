@@ -0,0 +1,265 @@
+//! Optional per-id Metadata of the ERC-6909 standard.
+//!
+//! Token ids without a name, symbol or decimals of their own fall back to the
+//! contract-wide defaults.
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{uint, FixedBytes, U256, U8};
+use stylus_sdk::{
+    evm,
+    prelude::*,
+    storage::{StorageBool, StorageMap, StorageString, StorageU8},
+};
+
+use crate::{
+    token::erc6909::traits::IErc6909Metadata,
+    utils::{introspection::erc165::IErc165, Metadata},
+};
+
+/// Number of decimals returned by default by [`Erc6909Metadata`].
+pub const DEFAULT_DECIMALS: U8 = uint!(18_U8);
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the name of token type `id` changes to `new_name`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909NameUpdated(uint256 indexed id, string new_name);
+
+        /// Emitted when the symbol of token type `id` changes to
+        /// `new_symbol`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909SymbolUpdated(uint256 indexed id, string new_symbol);
+
+        /// Emitted when the decimals of token type `id` change to
+        /// `new_decimals`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909DecimalsUpdated(uint256 indexed id, uint8 new_decimals);
+    }
+}
+
+pub use sol::*;
+
+/// State of an [`Erc6909Metadata`] contract.
+#[storage]
+pub struct Erc6909Metadata {
+    /// Default name and symbol for all token types.
+    pub(crate) metadata: Metadata,
+    /// Mapping from token id to its name.
+    pub(crate) names: StorageMap<U256, StorageString>,
+    /// Mapping from token id to its symbol.
+    pub(crate) symbols: StorageMap<U256, StorageString>,
+    /// Mapping from token id to its decimals.
+    pub(crate) decimals: StorageMap<U256, StorageU8>,
+    /// Mapping from token id to whether its decimals were set.
+    pub(crate) decimals_set: StorageMap<U256, StorageBool>,
+}
+
+#[public]
+#[implements(IErc6909Metadata, IErc165)]
+impl Erc6909Metadata {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `name` - Default token name.
+    /// * `symbol` - Default token symbol.
+    #[constructor]
+    pub fn constructor(&mut self, name: String, symbol: String) {
+        self.metadata.constructor(name, symbol);
+    }
+}
+
+#[public]
+impl IErc6909Metadata for Erc6909Metadata {
+    fn name(&self, id: U256) -> String {
+        let name = self.names.get(id).get_string();
+        if name.is_empty() {
+            self.metadata.name()
+        } else {
+            name
+        }
+    }
+
+    fn symbol(&self, id: U256) -> String {
+        let symbol = self.symbols.get(id).get_string();
+        if symbol.is_empty() {
+            self.metadata.symbol()
+        } else {
+            symbol
+        }
+    }
+
+    fn decimals(&self, id: U256) -> U8 {
+        if self.decimals_set.get(id) {
+            self.decimals.get(id)
+        } else {
+            DEFAULT_DECIMALS
+        }
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Metadata {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Metadata>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909Metadata {
+    /// Sets `name` as the name of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `name` - New name of the token.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909NameUpdated`].
+    pub fn _set_name(&mut self, id: U256, name: String) {
+        self.names.setter(id).set_str(name.clone());
+        evm::log(ERC6909NameUpdated { id, new_name: name });
+    }
+
+    /// Sets `symbol` as the symbol of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `symbol` - New symbol of the token.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909SymbolUpdated`].
+    pub fn _set_symbol(&mut self, id: U256, symbol: String) {
+        self.symbols.setter(id).set_str(symbol.clone());
+        evm::log(ERC6909SymbolUpdated { id, new_symbol: symbol });
+    }
+
+    /// Sets `decimals` as the number of decimals of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `decimals` - New number of decimals of the token.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909DecimalsUpdated`].
+    pub fn _set_decimals(&mut self, id: U256, decimals: U8) {
+        self.decimals.setter(id).set(decimals);
+        self.decimals_set.setter(id).set(true);
+        evm::log(ERC6909DecimalsUpdated {
+            id,
+            new_decimals: decimals.to::<u8>(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, Address, U256, U8};
+    use motsu::prelude::Contract;
+    use stylus_sdk::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Metadata {}
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const NAME: &str = "Liquidity Position";
+    const SYMBOL: &str = "LP";
+
+    #[motsu::test]
+    fn returns_defaults_when_not_set(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract.sender(alice).constructor(NAME.to_owned(), SYMBOL.to_owned());
+
+        assert_eq!(NAME, contract.sender(alice).name(TOKEN_ID));
+        assert_eq!(SYMBOL, contract.sender(alice).symbol(TOKEN_ID));
+        assert_eq!(DEFAULT_DECIMALS, contract.sender(alice).decimals(TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn returns_per_id_metadata_when_set(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        let other_id = uint!(2_U256);
+        contract.sender(alice).constructor(NAME.to_owned(), SYMBOL.to_owned());
+
+        contract.init(alice, |contract| {
+            contract._set_name(TOKEN_ID, "ETH/USDC".to_owned());
+            contract._set_symbol(TOKEN_ID, "LP-ETH-USDC".to_owned());
+            contract._set_decimals(TOKEN_ID, U8::ZERO);
+        });
+
+        assert_eq!("ETH/USDC", contract.sender(alice).name(TOKEN_ID));
+        assert_eq!("LP-ETH-USDC", contract.sender(alice).symbol(TOKEN_ID));
+        assert_eq!(U8::ZERO, contract.sender(alice).decimals(TOKEN_ID));
+
+        assert_eq!(NAME, contract.sender(alice).name(other_id));
+        assert_eq!(SYMBOL, contract.sender(alice).symbol(other_id));
+        assert_eq!(DEFAULT_DECIMALS, contract.sender(alice).decimals(other_id));
+    }
+
+    #[motsu::test]
+    fn setters_emit_events(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract._set_name(TOKEN_ID, NAME.to_owned());
+            contract._set_symbol(TOKEN_ID, SYMBOL.to_owned());
+            contract._set_decimals(TOKEN_ID, uint!(6_U8));
+        });
+
+        contract.assert_emitted(&ERC6909NameUpdated {
+            id: TOKEN_ID,
+            new_name: NAME.to_owned(),
+        });
+        contract.assert_emitted(&ERC6909SymbolUpdated {
+            id: TOKEN_ID,
+            new_symbol: SYMBOL.to_owned(),
+        });
+        contract.assert_emitted(&ERC6909DecimalsUpdated {
+            id: TOKEN_ID,
+            new_decimals: 6,
+        });
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Metadata as IErc6909Metadata>::interface_id();
+        let expected: FixedBytes<4> = 0x71abc795_u32.into();
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<Erc6909Metadata>, alice: Address) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Metadata as IErc6909Metadata>::interface_id()
+        ));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Metadata as IErc165>::interface_id()));
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -1,238 +1,359 @@
-#![no_std]
-extern crate alloc;
-
-use alloc::{vec, vec::Vec};
-
-use alloy_primitives::{Address, U256};
+//! Extension of ERC-6909 that adds storage based per-id URIs.
+//!
+//! Token ids without a URI of their own fall back to the
+//! [`IErc6909ContentUri::token_uri`] of the contract. URIs can only be set by
+//! the admin account chosen at construction.
+//!
+//! The admin is optional: a contract gating URIs through its own access
+//! control, such as [`crate::token::erc6909::composite_token::MyToken`], can
+//! skip [`Erc6909MetadataUri::constructor`] and call
+//! [`Erc6909MetadataUri::_set_token_uri`] after its own checks. The admin then
+//! stays [`Address::ZERO`], and [`Erc6909MetadataUri::only_admin`] rejects
+//! every caller.
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    prelude::{storage, HostAccess, StorageType},
-    storage::{StorageMap, StorageUint},
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageString},
 };
 
-use super::super::{
-    erc6909::Erc6909,
-    error::Error,
-    traits::{IErc6909, IErc6909Burnable, IErc6909Mintable, IErc6909Supply},
+use crate::{
+    token::erc6909::traits::{IErc6909ContentUri, IErc6909MetadataUri},
+    utils::introspection::erc165::IErc165,
 };
 
-/// ERC-6909 + per-ID total-supply extension.
-#[storage]
-pub struct Erc6909Supply {
-    /// The core ERC-6909 logic
-    pub base: Erc6909,
-    /// Tracks minted minus burned for each token ID
-    total_supplies: StorageMap<U256, StorageUint<256, 4>>,
-}
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
 
-// --------------------------------------------------------------------------
-// IErc6909Supply: just read out our `total_supplies` map
-// --------------------------------------------------------------------------
-impl IErc6909Supply for Erc6909Supply {
-    fn total_supply(&self, id: U256) -> U256 {
-        self.total_supplies.get(id)
+    sol! {
+        /// Emitted when the URI for token type `id` changes to `value`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event URI(string value, uint256 indexed id);
     }
-}
 
-// --------------------------------------------------------------------------
-// IErc6909: forward every core method to `base`
-// --------------------------------------------------------------------------
-impl IErc6909 for Erc6909Supply {
-    fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.base.balance_of(owner, id)
+    sol! {
+        /// The caller account is not authorized to set token URIs.
+        ///
+        /// * `account` - Account that tried to set a token URI.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedAccount(address account);
+
+        /// The admin is not a valid admin account (e.g. `Address::ZERO`).
+        ///
+        /// * `admin` - Account proposed as the admin.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidAdmin(address admin);
     }
+}
 
-    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.base.allowance(owner, spender, id)
-    }
+pub use sol::*;
 
-    fn is_operator(&self, owner: Address, operator: Address) -> bool {
-        self.base.is_operator(owner, operator)
-    }
+/// An [`Erc6909MetadataUri`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The caller account is not authorized to set token URIs.
+    UnauthorizedAccount(ERC6909UnauthorizedAccount),
+    /// The admin is not a valid admin account (e.g. `Address::ZERO`).
+    InvalidAdmin(ERC6909InvalidAdmin),
+}
 
-    fn approve(
-        &mut self,
-        caller: Address,
-        spender: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Error> {
-        self.base.approve(caller, spender, id, amount)
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
     }
+}
 
-    fn set_operator(
-        &mut self,
-        caller: Address,
-        operator: Address,
-        approved: bool,
-    ) -> Result<bool, Error> {
-        self.base.set_operator(caller, operator, approved)
+/// State of an [`Erc6909MetadataUri`] contract.
+#[storage]
+pub struct Erc6909MetadataUri {
+    /// Account allowed to set token URIs, or [`Address::ZERO`] if the
+    /// contract gates URIs through its own access control.
+    pub(crate) admin: StorageAddress,
+    /// Optional mapping for token URIs.
+    pub(crate) token_uris: StorageMap<U256, StorageString>,
+}
+
+#[public]
+#[implements(IErc6909MetadataUri<Error = Error>, IErc165)]
+impl Erc6909MetadataUri {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `admin` - Account allowed to set token URIs.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidAdmin`] - If `admin` is [`Address::ZERO`].
+    #[constructor]
+    pub fn constructor(&mut self, admin: Address) -> Result<(), Error> {
+        if admin.is_zero() {
+            return Err(Error::InvalidAdmin(ERC6909InvalidAdmin { admin }));
+        }
+        self.admin.set(admin);
+        Ok(())
     }
 
-    fn transfer(
-        &mut self,
-        caller: Address,
-        to: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Error> {
-        self.base.transfer(caller, to, id, amount)
+    /// Returns the account allowed to set token URIs.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    pub fn admin(&self) -> Address {
+        self.admin.get()
     }
+}
 
-    fn transfer_from(
-        &mut self,
-        caller: Address,
-        from: Address,
-        to: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Error> {
-        self.base.transfer_from(caller, from, to, id, amount)
+#[public]
+impl IErc6909MetadataUri for Erc6909MetadataUri {
+    type Error = Error;
+
+    #[selector(name = "setTokenURI")]
+    fn set_token_uri(&mut self, id: U256, uri: String) -> Result<(), Error> {
+        self.only_admin()?;
+        self._set_token_uri(id, uri);
+        Ok(())
     }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909Mintable: bump our total_supplies then mint balances
-// --------------------------------------------------------------------------
-impl IErc6909Mintable for Erc6909Supply {
-    fn mint(
-        &mut self,
-        caller: Address,
-        to: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<(), Error> {
-        // 1) update total_supplies[id] += amount
-        let old = self.total_supplies.get(id);
-        let new = old.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
-        self.total_supplies.insert(id, new);
-        // 2) mint the balance
-        self.base.mint(caller, to, id, amount)
+#[public]
+impl IErc165 for Erc6909MetadataUri {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909MetadataUri>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
     }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909Burnable: burn balances then decrement total_supplies
-// --------------------------------------------------------------------------
-impl IErc6909Burnable for Erc6909Supply {
-    fn burn(
-        &mut self,
-        caller: Address,
-        from: Address,
+impl Erc6909MetadataUri {
+    /// Returns the URI of token type `id`, or the URI provided by
+    /// `content_uri` if `id` has no URI of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `content_uri` - Read access to a contract providing
+    ///   [`IErc6909ContentUri`] interface.
+    pub fn token_uri(
+        &self,
         id: U256,
-        amount: U256,
-    ) -> Result<(), Error> {
-        // 1) burn the balance
-        self.base.burn(caller, from, id, amount)?;
-        // 2) decrement total_supplies[id]
-        let old = self.total_supplies.get(id);
-        // you may want to guard underflow here; core already checks balances
-        let new = old.checked_sub(amount).unwrap_or_default();
-        self.total_supplies.insert(id, new);
+        content_uri: &impl IErc6909ContentUri,
+    ) -> String {
+        let token_uri = self.token_uris.get(id).get_string();
+
+        if token_uri.is_empty() {
+            content_uri.token_uri(id)
+        } else {
+            token_uri
+        }
+    }
+
+    /// Checks if the [`msg::sender`] is set as the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If called by any account other than
+    ///   the admin.
+    pub fn only_admin(&self) -> Result<(), Error> {
+        let account = msg::sender();
+        if self.admin() != account {
+            return Err(Error::UnauthorizedAccount(
+                ERC6909UnauthorizedAccount { account },
+            ));
+        }
         Ok(())
     }
-}
 
+    /// Sets `uri` as the URI of token type `id`, without checking the
+    /// caller's rights.
+    ///
+    /// An empty `uri` makes `id` fall back to the contract's
+    /// [`IErc6909ContentUri::token_uri`] again.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `uri` - New URI of the token.
+    ///
+    /// # Events
+    ///
+    /// * [`URI`].
+    pub fn _set_token_uri(&mut self, id: U256, uri: String) {
+        self.token_uris.setter(id).set_str(uri.clone());
+        evm::log(URI { value: uri, id });
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::prelude::*;
+
     use super::*;
-    use alloy_primitives::{Address, U256};
-    use stylus_sdk::testing::TestVM;
+    use crate::token::erc6909::extensions::Erc6909ContentUri;
 
-    /// Instantiate the supply extension out of storage so we can call it like a normal Rust struct.
-    fn fresh_supply() -> Erc6909Supply {
-        let vm = TestVM::default();
-        Erc6909Supply::from(&vm)
+    #[storage]
+    struct Erc6909MetadataExample {
+        content_uri: Erc6909ContentUri,
+        metadata_uri: Erc6909MetadataUri,
     }
 
-    #[test]
-    fn mint_increases_total_supply() {
-        let mut ext = fresh_supply();
-        let caller = Address::new([0xAA; 20]);
-        let id     = U256::from(42u64);
-        let amount = U256::from(7u64);
-
-        ext.mint(caller, caller, id, amount).unwrap();
-        assert_eq!(ext.total_supply(id), amount);
+    #[public]
+    #[implements(IErc6909ContentUri, IErc6909MetadataUri<Error = Error>)]
+    impl Erc6909MetadataExample {}
+
+    #[public]
+    impl IErc6909ContentUri for Erc6909MetadataExample {
+        #[selector(name = "contractURI")]
+        fn contract_uri(&self) -> String {
+            self.content_uri.contract_uri()
+        }
+
+        #[selector(name = "tokenURI")]
+        fn token_uri(&self, id: U256) -> String {
+            self.metadata_uri.token_uri(id, &self.content_uri)
+        }
     }
 
-    #[test]
-    fn burn_decreases_total_supply() {
-        let mut ext = fresh_supply();
-        let caller = Address::new([0xAA; 20]);
-        let id     = U256::from(100u64);
-        ext.mint(caller, caller, id, U256::from(10u64)).unwrap();
-
-        ext.burn(caller, caller, id, U256::from(3u64)).unwrap();
-        assert_eq!(ext.total_supply(id), U256::from(7u64));
+    #[public]
+    impl IErc6909MetadataUri for Erc6909MetadataExample {
+        type Error = Error;
+
+        #[selector(name = "setTokenURI")]
+        fn set_token_uri(
+            &mut self,
+            id: U256,
+            uri: String,
+        ) -> Result<(), Error> {
+            self.metadata_uri.set_token_uri(id, uri)
+        }
     }
 
-    #[test]
-    fn mint_reverts_for_zero_caller_or_receiver() {
-        let mut ext = fresh_supply();
-        let zero = Address::new([0u8; 20]);
-        let id   = U256::ONE;
-
-        assert_eq!(
-            ext.mint(zero, zero, id, U256::ONE),
-            Err(Error::InvalidApprover)
-        );
-    }
+    unsafe impl TopLevelStorage for Erc6909MetadataExample {}
+    unsafe impl TopLevelStorage for Erc6909MetadataUri {}
 
-    #[test]
-    fn burn_reverts_when_underflow() {
-        let mut ext = fresh_supply();
-        let caller = Address::new([0xAA; 20]);
-        let id     = U256::from(5u64);
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const BASE_URI: &str = "https://some.base.uri/{id}.json";
 
-        // burning without minting first
-        assert_eq!(
-            ext.burn(caller, caller, id, U256::ONE),
-            Err(Error::InsufficientBalance)
-        );
+    #[motsu::test]
+    fn constructor_sets_admin(
+        contract: Contract<Erc6909MetadataUri>,
+        alice: Address,
+    ) {
+        contract.sender(alice).constructor(alice).expect("should construct");
+        assert_eq!(alice, contract.sender(alice).admin());
     }
-}
 
-// ——————————————————————————————————————————————————————————————————————————
-// motsu-driven Unit tests
-// ——————————————————————————————————————————————————————————————————————————
-#[cfg(feature = "motsu")]
-#[cfg(test)]
-mod motsu_tests {
-    use super::*;
-    use alloy_primitives::U256;
-    use stylus_sdk::{prelude::*, testing::TestVM};
-    use motsu::prelude::*;
-
-    // let Motsu know how to snapshot & rollback storage
-    #[cfg_attr(feature = "metadata_uri", entrypoint)]
-    unsafe impl stylus_sdk::testing::TopLevelStorage for Erc6909MetadataUri {}
+    #[motsu::test]
+    fn constructor_reverts_for_zero_admin(
+        contract: Contract<Erc6909MetadataUri>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .constructor(Address::ZERO)
+            .expect_err("should not construct with the zero admin");
+        assert!(matches!(
+            err,
+            Error::InvalidAdmin(ERC6909InvalidAdmin { admin })
+                if admin.is_zero()
+        ));
+    }
 
-    fn fresh_contract() -> Contract<Erc6909MetadataUri> {
-        let vm = TestVM::default();
-        Contract::<Erc6909MetadataUri>::new(&vm)
+    #[motsu::test]
+    fn token_uri_falls_back_to_content_uri(
+        contract: Contract<Erc6909MetadataExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.content_uri.uri.set_str(BASE_URI.to_owned());
+        });
+
+        assert_eq!(BASE_URI, contract.sender(alice).token_uri(TOKEN_ID));
     }
 
     #[motsu::test]
-    fn set_and_read_back(mut c: Contract<Erc6909MetadataUri>, samuel: Address) {
-        let id  = U256::from(42u64);
-        let uri = b"motsu://token/42".to_vec();
+    fn token_uri_overrides_content_uri(
+        contract: Contract<Erc6909MetadataExample>,
+        alice: Address,
+    ) {
+        let token_uri = "ipfs://some/token/uri";
+        contract.init(alice, |contract| {
+            contract.content_uri.uri.set_str(BASE_URI.to_owned());
+            contract.metadata_uri.admin.set(alice);
+        });
+
+        contract
+            .sender(alice)
+            .set_token_uri(TOKEN_ID, token_uri.to_owned())
+            .expect("should set token URI");
+
+        assert_eq!(token_uri, contract.sender(alice).token_uri(TOKEN_ID));
+        assert_eq!(BASE_URI, contract.sender(alice).token_uri(uint!(2_U256)));
+        contract
+            .assert_emitted(&URI { value: token_uri.to_owned(), id: TOKEN_ID });
+    }
 
-        // samuel (non-zero) sets the URI
-        c.sender(samuel)
-        .set_token_uri(samuel, id, uri.clone())
-        .motsu_unwrap();
+    #[motsu::test]
+    fn set_token_uri_reverts_for_non_admin(
+        contract: Contract<Erc6909MetadataExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.content_uri.uri.set_str(BASE_URI.to_owned());
+            contract.metadata_uri.admin.set(alice);
+        });
+
+        let err = contract
+            .sender(bob)
+            .set_token_uri(TOKEN_ID, "ipfs://bob".to_owned())
+            .expect_err("should not set token URI as Bob");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(ERC6909UnauthorizedAccount { account })
+                if account == bob
+        ));
+        assert_eq!(BASE_URI, contract.sender(alice).token_uri(TOKEN_ID));
+    }
 
-        // And reading via the public getter returns the same bytes
-        let got = c.token_uri(id);
-        assert_eq!(got, uri);
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909MetadataUri as IErc6909MetadataUri>::interface_id();
+        let expected: FixedBytes<4> = 0x162094c4_u32.into();
+        assert_eq!(actual, expected);
     }
 
     #[motsu::test]
-    fn zero_caller_fails(mut c: Contract<Erc6909MetadataUri>) {
-        let zero = Address::new([0;20]);
-        c.sender(zero)
-        .set_token_uri(zero, U256::from(1u64), b"bad".to_vec())
-        .motsu_unwrap_err();
+    fn supports_interface(
+        contract: Contract<Erc6909MetadataUri>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909MetadataUri as IErc6909MetadataUri>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909MetadataUri as IErc165>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
     }
 }
@@ -0,0 +1,389 @@
+//! Extensions of ERC-6909 that restrict minting to authorized accounts.
+//!
+//! [`Erc6909RoleMintable`] lets accounts holding [`MINTER_ROLE`] mint any
+//! token type, and accounts holding the [`minter_role`] of a token id mint
+//! that token type only. [`Erc6909OwnableMintable`] is a simpler variant for
+//! deployments where only the [`Ownable`] owner mints.
+//!
+//! Both are layered over the [`Erc6909Update`] hook, so the minted tokens go
+//! through every other extension of the contract.
+use alloy_primitives::{keccak256, Address, B256, U256};
+
+use crate::{
+    access::{
+        control::{self, AccessControl, IAccessControl},
+        ownable::{self, IOwnable, Ownable, OwnableUnauthorizedAccount},
+    },
+    token::erc6909::erc6909::Erc6909Update,
+};
+
+/// Role allowed to mint tokens of every type.
+pub const MINTER_ROLE: [u8; 32] =
+    keccak_const::Keccak256::new().update(b"MINTER_ROLE").finalize();
+
+/// Returns the role allowed to mint tokens of type `id` only, computed as
+/// `keccak256(abi.encode(MINTER_ROLE, id))`.
+///
+/// # Arguments
+///
+/// * `id` - Token id as a number.
+#[must_use]
+pub fn minter_role(id: U256) -> B256 {
+    keccak256([MINTER_ROLE, id.to_be_bytes::<32>()].concat())
+}
+
+/// ERC-6909 minting restricted to accounts holding [`MINTER_ROLE`], or the
+/// [`minter_role`] of the minted token id.
+pub trait Erc6909RoleMintable: Erc6909Update
+where
+    Self::Error: From<control::Error>,
+{
+    /// Returns the [`AccessControl`] contract holding the minter roles.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn access_control(&self) -> &AccessControl;
+
+    /// Checks that `account` may mint tokens of type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account that mints the tokens.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`control::Error::UnauthorizedAccount`] - If `account` holds neither
+    ///   [`MINTER_ROLE`] nor the [`minter_role`] of `id`. The error reports the
+    ///   latter as the needed role.
+    fn _check_minter(
+        &self,
+        account: Address,
+        id: U256,
+    ) -> Result<(), control::Error> {
+        let access = self.access_control();
+        if access.has_role(MINTER_ROLE.into(), account) {
+            return Ok(());
+        }
+        access._check_role(minter_role(id), account)
+    }
+
+    /// Creates an `amount` of tokens of type `id` and assigns them to `to`,
+    /// provided `minter` may mint them.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `minter` - Account that mints the tokens.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`control::Error::UnauthorizedAccount`] - If `minter` holds neither
+    ///   [`MINTER_ROLE`] nor the [`minter_role`] of `id`.
+    /// * [`crate::token::erc6909::Error::InvalidReceiver`] - If `to` is
+    ///   [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _authorized_mint(
+        &mut self,
+        minter: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._check_minter(minter, id)?;
+        self._mint(to, id, amount)
+    }
+}
+
+/// ERC-6909 minting restricted to the [`Ownable`] owner.
+pub trait Erc6909OwnableMintable: Erc6909Update
+where
+    Self::Error: From<ownable::Error>,
+{
+    /// Returns the [`Ownable`] contract holding the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn ownable(&self) -> &Ownable;
+
+    /// Creates an `amount` of tokens of type `id` and assigns them to `to`,
+    /// provided `minter` is the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `minter` - Account that mints the tokens.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`ownable::Error::UnauthorizedAccount`] - If `minter` is not the
+    ///   owner.
+    /// * [`crate::token::erc6909::Error::InvalidReceiver`] - If `to` is
+    ///   [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _authorized_mint(
+        &mut self,
+        minter: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        if self.ownable().owner() != minter {
+            return Err(ownable::Error::UnauthorizedAccount(
+                OwnableUnauthorizedAccount { account: minter },
+            )
+            .into());
+        }
+        self._mint(to, id, amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{b256, uint, Address, U256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::{msg, prelude::*};
+
+    use super::*;
+    use crate::{
+        access::control::AccessControlUnauthorizedAccount,
+        token::erc6909::{erc6909::Erc6909, error, traits::IErc6909},
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    #[derive(Debug)]
+    enum Error {
+        Erc6909(error::Error),
+        AccessControl(control::Error),
+        Ownable(ownable::Error),
+    }
+
+    impl From<error::Error> for Error {
+        fn from(value: error::Error) -> Self {
+            Error::Erc6909(value)
+        }
+    }
+
+    impl From<control::Error> for Error {
+        fn from(value: control::Error) -> Self {
+            Error::AccessControl(value)
+        }
+    }
+
+    impl From<ownable::Error> for Error {
+        fn from(value: ownable::Error) -> Self {
+            Error::Ownable(value)
+        }
+    }
+
+    #[storage]
+    struct RoleMintableToken {
+        erc6909: Erc6909,
+        access: AccessControl,
+    }
+
+    #[public]
+    impl RoleMintableToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.erc6909.balance_of(owner, id)
+        }
+    }
+
+    unsafe impl TopLevelStorage for RoleMintableToken {}
+
+    impl Erc6909Update for RoleMintableToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.erc6909._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909RoleMintable for RoleMintableToken {
+        fn access_control(&self) -> &AccessControl {
+            &self.access
+        }
+    }
+
+    #[storage]
+    struct OwnableMintableToken {
+        erc6909: Erc6909,
+        ownable: Ownable,
+    }
+
+    #[public]
+    impl OwnableMintableToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.erc6909.balance_of(owner, id)
+        }
+    }
+
+    unsafe impl TopLevelStorage for OwnableMintableToken {}
+
+    impl Erc6909Update for OwnableMintableToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.erc6909._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909OwnableMintable for OwnableMintableToken {
+        fn ownable(&self) -> &Ownable {
+            &self.ownable
+        }
+    }
+
+    #[motsu::test]
+    fn minter_role_mints_every_id(
+        contract: Contract<RoleMintableToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.access._grant_role(MINTER_ROLE.into(), alice);
+        });
+
+        contract
+            .sender(alice)
+            ._authorized_mint(alice, alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint as minter");
+        contract
+            .sender(alice)
+            ._authorized_mint(alice, alice, uint!(2_U256), uint!(10_U256))
+            .expect("should mint another id as minter");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn per_id_minter_role_mints_its_id_only(
+        contract: Contract<RoleMintableToken>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.access._grant_role(minter_role(TOKEN_ID), alice);
+        });
+
+        contract
+            .sender(alice)
+            ._authorized_mint(alice, alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint `TOKEN_ID` as its minter");
+        let err = contract
+            .sender(alice)
+            ._authorized_mint(alice, alice, uint!(2_U256), uint!(10_U256))
+            .expect_err("should not mint another id");
+        assert!(matches!(
+            err,
+            Error::AccessControl(control::Error::UnauthorizedAccount(
+                AccessControlUnauthorizedAccount { account, needed_role }
+            )) if account == alice && needed_role == minter_role(uint!(2_U256))
+        ));
+    }
+
+    #[motsu::test]
+    fn owner_mints(contract: Contract<OwnableMintableToken>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract
+                .ownable
+                .constructor(msg::sender())
+                .expect("should construct");
+        });
+
+        contract
+            .sender(alice)
+            ._authorized_mint(alice, alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint as owner");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn non_owner_cannot_mint(
+        contract: Contract<OwnableMintableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .ownable
+                .constructor(msg::sender())
+                .expect("should construct");
+        });
+
+        let err = contract
+            .sender(bob)
+            ._authorized_mint(bob, bob, TOKEN_ID, uint!(10_U256))
+            .expect_err("should not mint as Bob");
+        assert!(matches!(
+            err,
+            Error::Ownable(ownable::Error::UnauthorizedAccount(
+                OwnableUnauthorizedAccount { account }
+            )) if account == bob
+        ));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn minter_role_is_stable() {
+        assert_eq!(
+            minter_role(TOKEN_ID),
+            b256!(
+                "64e21244e91af723e1b962171ed4828dcecc0d7b89872e516a5db8266da80000"
+            )
+        );
+        assert_ne!(minter_role(TOKEN_ID), minter_role(uint!(2_U256)));
+    }
+}
@@ -1,14 +1,26 @@
-#![no_std]
-
+pub mod capped;
+pub mod collection_wrapper;
+pub mod content_uri;
+pub mod enumerable;
+pub mod facade;
+pub mod flash_loan;
+pub mod metadata;
 pub mod metadata_uri;
+pub mod mintable;
+pub mod pausable;
+pub mod permit;
 pub mod token_supply;
-pub mod enumerable;
-
+pub mod wrapper;
 
 // only re-export the _types_ here; the traits stay in the top-level
 // `traits.rs`
+pub use capped::Erc6909Capped;
+pub use content_uri::Erc6909ContentUri;
+pub use enumerable::Erc6909Enumerable;
+pub use facade::{Erc20Facade, Erc6909Facades};
+pub use flash_loan::Erc6909FlashLoans;
+pub use metadata::Erc6909Metadata;
 pub use metadata_uri::Erc6909MetadataUri;
+pub use permit::Erc6909Permit;
 pub use token_supply::Erc6909Supply;
-pub use enumerable::Erc6909Enumerable;
-
-
+pub use wrapper::Erc6909Wrapper;
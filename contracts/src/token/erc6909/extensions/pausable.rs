@@ -0,0 +1,331 @@
+//! Extension of ERC-6909 that allows to stop token transfers, mints and burns
+//! in an emergency.
+//!
+//! A contract implementing [`Erc6909Pausable`] calls
+//! [`Erc6909Pausable::_check_not_paused`] first thing in its
+//! [`Erc6909Update::_update`] hook, so every balance change, including batch
+//! operations and the flows of wrapping extensions, reverts with
+//! [`pausable::Error::EnforcedPause`] while the contract is paused.
+//! Approvals and operator changes stay available.
+//!
+//! The contract decides who may pause and unpause by implementing
+//! [`Erc6909Pausable::_check_pauser`], e.g. checking [`PAUSER_ROLE`] or the
+//! [`crate::access::ownable::Ownable`] owner.
+use alloy_primitives::Address;
+
+use crate::{
+    token::erc6909::erc6909::Erc6909Update,
+    utils::pausable::{self, Pausable},
+};
+
+/// Role allowed to pause and unpause token transfers.
+pub const PAUSER_ROLE: [u8; 32] =
+    keccak_const::Keccak256::new().update(b"PAUSER_ROLE").finalize();
+
+/// ERC-6909 token with pausable transfers, mints and burns.
+pub trait Erc6909Pausable: Erc6909Update
+where
+    Self::Error: From<pausable::Error>,
+{
+    /// Returns the [`Pausable`] contract holding the paused state.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn pausable(&self) -> &Pausable;
+
+    /// Returns the [`Pausable`] contract holding the paused state.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    fn pausable_mut(&mut self) -> &mut Pausable;
+
+    /// Checks that `account` may pause and unpause the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account that pauses or unpauses the contract.
+    ///
+    /// # Errors
+    ///
+    /// * If `account` is not allowed to pause and unpause the contract.
+    fn _check_pauser(&self, account: Address) -> Result<(), Self::Error>;
+
+    /// Checks that the contract is not paused. Must be called from
+    /// [`Erc6909Update::_update`] before any balance changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`pausable::Error::EnforcedPause`] - If the contract is in the
+    ///   `Paused` state.
+    fn _check_not_paused(&self) -> Result<(), Self::Error> {
+        Ok(self.pausable().when_not_paused()?)
+    }
+
+    /// Triggers `Paused` state, provided `account` may pause the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account that pauses the contract.
+    ///
+    /// # Errors
+    ///
+    /// * If `account` is not allowed to pause the contract.
+    /// * [`pausable::Error::EnforcedPause`] - If the contract is in the
+    ///   `Paused` state.
+    ///
+    /// # Events
+    ///
+    /// * [`pausable::Paused`].
+    fn _pause(&mut self, account: Address) -> Result<(), Self::Error> {
+        self._check_pauser(account)?;
+        Ok(self.pausable_mut().pause()?)
+    }
+
+    /// Triggers `Unpaused` state, provided `account` may unpause the
+    /// contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account that unpauses the contract.
+    ///
+    /// # Errors
+    ///
+    /// * If `account` is not allowed to unpause the contract.
+    /// * [`pausable::Error::ExpectedPause`] - If the contract is in the
+    ///   `Unpaused` state.
+    ///
+    /// # Events
+    ///
+    /// * [`pausable::Unpaused`].
+    fn _unpause(&mut self, account: Address) -> Result<(), Self::Error> {
+        self._check_pauser(account)?;
+        Ok(self.pausable_mut().unpause()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::{msg, prelude::*};
+
+    use super::*;
+    use crate::{
+        access::ownable::{
+            self, IOwnable, Ownable, OwnableUnauthorizedAccount,
+        },
+        token::erc6909::{erc6909::Erc6909, error, traits::IErc6909},
+        utils::pausable::{EnforcedPause, IPausable},
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    #[derive(Debug)]
+    enum Error {
+        Erc6909(error::Error),
+        Pausable(pausable::Error),
+        Ownable(ownable::Error),
+    }
+
+    impl From<error::Error> for Error {
+        fn from(value: error::Error) -> Self {
+            Error::Erc6909(value)
+        }
+    }
+
+    impl From<pausable::Error> for Error {
+        fn from(value: pausable::Error) -> Self {
+            Error::Pausable(value)
+        }
+    }
+
+    impl From<ownable::Error> for Error {
+        fn from(value: ownable::Error) -> Self {
+            Error::Ownable(value)
+        }
+    }
+
+    #[storage]
+    struct PausableToken {
+        erc6909: Erc6909,
+        pausable: Pausable,
+        ownable: Ownable,
+    }
+
+    #[public]
+    impl PausableToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.erc6909.balance_of(owner, id)
+        }
+
+        fn paused(&self) -> bool {
+            self.pausable.paused()
+        }
+    }
+
+    unsafe impl TopLevelStorage for PausableToken {}
+
+    impl Erc6909Update for PausableToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            self._check_not_paused()?;
+            Ok(self.erc6909._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909Pausable for PausableToken {
+        fn pausable(&self) -> &Pausable {
+            &self.pausable
+        }
+
+        fn pausable_mut(&mut self) -> &mut Pausable {
+            &mut self.pausable
+        }
+
+        fn _check_pauser(&self, account: Address) -> Result<(), Error> {
+            if self.ownable.owner() != account {
+                return Err(ownable::Error::UnauthorizedAccount(
+                    OwnableUnauthorizedAccount { account },
+                )
+                .into());
+            }
+            Ok(())
+        }
+    }
+
+    fn deploy(contract: &Contract<PausableToken>, owner: Address) {
+        contract.init(owner, |contract| {
+            contract
+                .ownable
+                .constructor(msg::sender())
+                .expect("should construct");
+            contract
+                ._mint(owner, TOKEN_ID, uint!(10_U256))
+                .expect("should mint tokens for the owner");
+        });
+    }
+
+    fn assert_enforced_pause(err: Error) {
+        assert!(matches!(
+            err,
+            Error::Pausable(pausable::Error::EnforcedPause(EnforcedPause {}))
+        ));
+    }
+
+    #[motsu::test]
+    fn paused_token_reverts_balance_changes(
+        contract: Contract<PausableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&contract, alice);
+        contract.sender(alice)._pause(alice).expect("should pause as owner");
+        assert!(contract.sender(alice).paused());
+
+        let err = contract
+            .sender(alice)
+            ._transfer(alice, bob, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not transfer while paused");
+        assert_enforced_pause(err);
+
+        let err = contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not mint while paused");
+        assert_enforced_pause(err);
+
+        let err = contract
+            .sender(alice)
+            ._burn(alice, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not burn while paused");
+        assert_enforced_pause(err);
+
+        let err = contract
+            .sender(alice)
+            ._batch_transfer_from(
+                alice,
+                alice,
+                bob,
+                vec![TOKEN_ID],
+                vec![uint!(1_U256)],
+            )
+            .expect_err("should not transfer a batch while paused");
+        assert_enforced_pause(err);
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn unpaused_token_moves_balances(
+        contract: Contract<PausableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&contract, alice);
+        contract.sender(alice)._pause(alice).expect("should pause as owner");
+        contract
+            .sender(alice)
+            ._unpause(alice)
+            .expect("should unpause as owner");
+        assert!(!contract.sender(alice).paused());
+
+        contract
+            .sender(alice)
+            ._transfer(alice, bob, TOKEN_ID, uint!(4_U256))
+            .expect("should transfer when unpaused");
+        assert_eq!(
+            uint!(4_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn only_pauser_can_pause(
+        contract: Contract<PausableToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        deploy(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            ._pause(bob)
+            .expect_err("should not pause as Bob");
+        assert!(matches!(
+            err,
+            Error::Ownable(ownable::Error::UnauthorizedAccount(
+                OwnableUnauthorizedAccount { account }
+            )) if account == bob
+        ));
+        assert!(!contract.sender(alice).paused());
+    }
+}
@@ -0,0 +1,932 @@
+//! Permit Contract.
+//!
+//! Extension of the ERC-6909 standard allowing approvals of a single token
+//! type to be made via signatures, in the spirit of [ERC-2612].
+//!
+//! Adds the `permit` method, which can be used to change an account’s
+//! allowance of a token id (see
+//! [`crate::token::erc6909::IErc6909::allowance`]) by presenting a message
+//! signed by the account. By not relying on
+//! [`crate::token::erc6909::IErc6909::approve`], the token holder account
+//! doesn’t need to send a transaction, and thus is not required to hold Ether
+//! at all.
+//!
+//! Also adds the `set_operator_by_sig` method, which grants or revokes
+//! operator status given a signed `OperatorApproval` of the owner. Contract
+//! owners sign through [ERC-1271].
+//!
+//! [ERC-2612]: https://eips.ethereum.org/EIPS/eip-2612
+//! [ERC-1271]: https://eips.ethereum.org/EIPS/eip-1271
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256, U8};
+use alloy_sol_types::SolType;
+use stylus_sdk::{
+    abi::Bytes, block, call::MethodError, function_selector, prelude::*,
+};
+
+use crate::{
+    token::erc6909::{self, error, Erc6909},
+    utils::{
+        cryptography::{
+            ecdsa::{self, ECDSAInvalidSignature, ECDSAInvalidSignatureS},
+            eip712::IEip712,
+            signature_checker,
+        },
+        nonces::{INonces, Nonces},
+    },
+};
+
+const PERMIT_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"Permit(address owner,address spender,uint256 id,uint256 amount,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+const OPERATOR_APPROVAL_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"OperatorApproval(address owner,address operator,bool approved,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+pub use sol::*;
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    pub(crate) type StructHashTuple = sol! {
+        tuple(bytes32, address, address, uint256, uint256, uint256, uint256)
+    };
+
+    pub(crate) type OperatorApprovalHashTuple = sol! {
+        tuple(bytes32, address, address, bool, uint256, uint256)
+    };
+
+    sol! {
+        /// Indicates an error related to the fact that
+        /// permit deadline has expired.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ExpiredSignature(uint256 deadline);
+
+        /// Indicates an error related to the issue about mismatched signature.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidSigner(address signer, address owner);
+
+        /// Indicates that `signature` is not a valid `OperatorApproval` of
+        /// `owner`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidOperatorSignature(address owner, address operator);
+    }
+}
+
+/// A Permit error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the fact that
+    /// permit deadline has expired.
+    ExpiredSignature(ERC6909ExpiredSignature),
+    /// Indicates an error related to the issue about mismatched signature.
+    InvalidSigner(ERC6909InvalidSigner),
+    /// Indicates that a signature is not a valid `OperatorApproval` of the
+    /// owner.
+    InvalidOperatorSignature(ERC6909InvalidOperatorSignature),
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(error::ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(error::ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(error::ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(error::ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+    /// The signature derives the [`Address::ZERO`].
+    InvalidSignature(ECDSAInvalidSignature),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(ECDSAInvalidSignatureS),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl From<ecdsa::Error> for Error {
+    fn from(value: ecdsa::Error) -> Self {
+        match value {
+            ecdsa::Error::InvalidSignature(e) => Error::InvalidSignature(e),
+            ecdsa::Error::InvalidSignatureS(e) => Error::InvalidSignatureS(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Permit`] Contract.
+#[storage]
+pub struct Erc6909Permit<T: IEip712 + StorageType> {
+    /// Contract implementing [`IEip712`] trait.
+    pub(crate) eip712: T,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl<T: IEip712 + StorageType> TopLevelStorage for Erc6909Permit<T> {}
+
+/// Interface for [`Erc6909Permit`]
+pub trait IErc6909Permit: INonces {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    // Calculated manually to include [`INonces::nonces`].
+    /// Solidity interface id associated with [`IErc6909Permit`] trait.
+    /// Computed as a XOR of selectors for each function in the trait.
+    #[must_use]
+    fn interface_id() -> FixedBytes<4>
+    where
+        Self: Sized,
+    {
+        FixedBytes::<4>::new(function_selector!("DOMAIN_SEPARATOR",))
+            ^ FixedBytes::<4>::new(function_selector!("nonces", Address,))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "permit", Address, Address, U256, U256, U256, U8, B256, B256
+            ))
+    }
+
+    /// Returns the domain separator used in the encoding of the signature for
+    /// [`Self::permit`], as defined by EIP712.
+    ///
+    /// NOTE: The implementation should use `#[selector(name =
+    /// "DOMAIN_SEPARATOR")]` to match Solidity's camelCase naming
+    /// convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    fn domain_separator(&self) -> B256;
+
+    /// Sets `amount` as the allowance of `spender` over `owner`'s tokens of
+    /// type `id`, given `owner`'s signed approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - The number of tokens of type `id` being permitted to
+    ///   transfer by `spender`.
+    /// * `deadline` - Deadline for the permit action.
+    /// * `v` - v value from the `owner`'s signature.
+    /// * `r` - r value from the `owner`'s signature.
+    /// * `s` - s value from the `owner`'s signature.
+    ///
+    /// # Errors
+    ///
+    /// * [`ERC6909ExpiredSignature`] - If the `deadline` param is from the
+    ///   past.
+    /// * [`ERC6909InvalidSigner`] - If signer is not an `owner`.
+    /// * [`ecdsa::Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`ecdsa::Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`erc6909::Error::InvalidSpender`] - If the `spender` address is
+    ///   [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::Approval`]
+    #[allow(clippy::too_many_arguments)]
+    fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Interface for granting operators of an [`Erc6909Permit`] contract through
+/// signatures.
+pub trait IErc6909OperatorSignature: INonces {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    // Calculated manually to include [`INonces::nonces`].
+    /// Solidity interface id associated with [`IErc6909OperatorSignature`]
+    /// trait. Computed as a XOR of selectors for each function in the trait.
+    #[must_use]
+    fn interface_id() -> FixedBytes<4>
+    where
+        Self: Sized,
+    {
+        FixedBytes::<4>::new(function_selector!("nonces", Address,))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "setOperatorBySig",
+                Address,
+                Address,
+                bool,
+                U256,
+                Bytes
+            ))
+    }
+
+    /// Grants or revokes operator permission of `operator` over all of
+    /// `owner`'s tokens, given `owner`'s signed `OperatorApproval`.
+    ///
+    /// The signature is checked with [`ecdsa::recover`] if `owner` is an
+    /// externally owned account, and through ERC-1271 if it is a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `operator` - Account to add to the set of authorized operators.
+    /// * `approved` - Flag that determines whether or not permission will be
+    ///   granted to `operator`.
+    /// * `deadline` - Deadline for the operator approval.
+    /// * `signature` - `owner`'s signature, encoded as `r ‖ s ‖ v` for
+    ///   externally owned accounts.
+    ///
+    /// # Errors
+    ///
+    /// * [`ERC6909ExpiredSignature`] - If the `deadline` param is from the
+    ///   past.
+    /// * [`ERC6909InvalidOperatorSignature`] - If `signature` is not a valid
+    ///   `OperatorApproval` of `owner`.
+    /// * [`erc6909::Error::InvalidApprover`] - If `owner` is [`Address::ZERO`].
+    /// * [`erc6909::Error::InvalidSpender`] - If `operator` is
+    ///   [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::OperatorSet`]
+    fn set_operator_by_sig(
+        &mut self,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<bool, Self::Error>;
+}
+
+impl<T: IEip712 + StorageType> Erc6909Permit<T> {
+    /// See [`IErc6909Permit::domain_separator`].
+    #[must_use]
+    pub fn domain_separator(&self) -> B256 {
+        self.eip712.domain_separator_v4()
+    }
+
+    /// See [`IErc6909Permit::permit`].
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+        erc6909: &mut Erc6909,
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(ERC6909ExpiredSignature { deadline }.into());
+        }
+
+        let struct_hash = keccak256(StructHashTuple::abi_encode(&(
+            PERMIT_TYPEHASH,
+            owner,
+            spender,
+            id,
+            amount,
+            nonces.use_nonce(owner),
+            deadline,
+        )));
+
+        let hash: B256 = self.eip712.hash_typed_data_v4(struct_hash);
+
+        let signer: Address = ecdsa::recover(self, hash, v, r, s)?;
+
+        if signer != owner {
+            return Err(ERC6909InvalidSigner { signer, owner }.into());
+        }
+
+        erc6909._approve(owner, spender, id, amount)?;
+
+        Ok(())
+    }
+
+    /// See [`IErc6909OperatorSignature::set_operator_by_sig`].
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn set_operator_by_sig(
+        &mut self,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+        deadline: U256,
+        signature: &Bytes,
+        erc6909: &mut Erc6909,
+        nonces: &mut Nonces,
+    ) -> Result<bool, Error> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(ERC6909ExpiredSignature { deadline }.into());
+        }
+
+        let struct_hash = keccak256(OperatorApprovalHashTuple::abi_encode(&(
+            OPERATOR_APPROVAL_TYPEHASH,
+            owner,
+            operator,
+            approved,
+            nonces.use_nonce(owner),
+            deadline,
+        )));
+
+        let hash: B256 = self.eip712.hash_typed_data_v4(struct_hash);
+
+        if !signature_checker::is_valid_signature_now(
+            self, owner, hash, signature,
+        ) {
+            return Err(
+                ERC6909InvalidOperatorSignature { owner, operator }.into()
+            );
+        }
+
+        erc6909._set_operator(owner, operator, approved)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::signers::{local::PrivateKeySigner, SignerSync};
+    use alloy_primitives::{uint, Address, U256};
+    use alloy_sol_types::SolValue;
+    use motsu::prelude::Contract;
+    use stylus_sdk::{prelude::*, storage::StorageBool};
+
+    use super::*;
+    use crate::{
+        token::erc6909::{Approval, IErc6909},
+        utils::cryptography::eip712::to_typed_data_hash,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    // Saturday, 1 January 2000 00:00:00
+    const EXPIRED_DEADLINE: U256 = uint!(946_684_800_U256);
+
+    #[storage]
+    struct Eip712;
+
+    impl IEip712 for Eip712 {
+        const NAME: &'static str = "ERC-6909 Permit Example";
+        const VERSION: &'static str = "1";
+    }
+
+    #[storage]
+    struct Erc6909PermitExample {
+        erc6909: Erc6909,
+        nonces: Nonces,
+        permit: Erc6909Permit<Eip712>,
+    }
+
+    #[public]
+    #[implements(
+        INonces,
+        IErc6909Permit<Error = Error>,
+        IErc6909OperatorSignature<Error = Error>
+    )]
+    impl Erc6909PermitExample {
+        fn allowance(
+            &self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+        ) -> U256 {
+            self.erc6909.allowance(owner, spender, id)
+        }
+
+        fn is_operator(&self, owner: Address, spender: Address) -> bool {
+            self.erc6909.is_operator(owner, spender)
+        }
+    }
+
+    #[public]
+    impl INonces for Erc6909PermitExample {
+        fn nonces(&self, owner: Address) -> U256 {
+            self.nonces.nonces(owner)
+        }
+    }
+
+    #[public]
+    impl IErc6909Permit for Erc6909PermitExample {
+        type Error = Error;
+
+        #[selector(name = "DOMAIN_SEPARATOR")]
+        fn domain_separator(&self) -> B256 {
+            self.permit.domain_separator()
+        }
+
+        fn permit(
+            &mut self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+            amount: U256,
+            deadline: U256,
+            v: u8,
+            r: B256,
+            s: B256,
+        ) -> Result<(), Error> {
+            self.permit.permit(
+                owner,
+                spender,
+                id,
+                amount,
+                deadline,
+                v,
+                r,
+                s,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    #[public]
+    impl IErc6909OperatorSignature for Erc6909PermitExample {
+        type Error = Error;
+
+        fn set_operator_by_sig(
+            &mut self,
+            owner: Address,
+            operator: Address,
+            approved: bool,
+            deadline: U256,
+            signature: Bytes,
+        ) -> Result<bool, Error> {
+            self.permit.set_operator_by_sig(
+                owner,
+                operator,
+                approved,
+                deadline,
+                &signature,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909PermitExample {}
+
+    /// Returns the EIP-712 digest of a `Permit` of `contract`.
+    fn permit_hash(
+        contract: &Contract<Erc6909PermitExample>,
+        owner: Address,
+        spender: Address,
+        nonce: U256,
+        deadline: U256,
+    ) -> B256 {
+        let struct_hash = keccak256(StructHashTuple::abi_encode(&(
+            PERMIT_TYPEHASH,
+            owner,
+            spender,
+            TOKEN_ID,
+            AMOUNT,
+            nonce,
+            deadline,
+        )));
+        let domain_separator = contract.sender(owner).domain_separator();
+        to_typed_data_hash(&domain_separator, &struct_hash)
+    }
+
+    /// Signs `hash` with `signer`, returning the `v`, `r` and `s` values.
+    fn sign(signer: &PrivateKeySigner, hash: B256) -> (u8, B256, B256) {
+        let signature =
+            signer.sign_hash_sync(&hash).expect("should sign the hash");
+        let bytes = signature.as_bytes();
+        (
+            bytes[64],
+            B256::from_slice(&bytes[..32]),
+            B256::from_slice(&bytes[32..64]),
+        )
+    }
+
+    #[motsu::test]
+    fn domain_separator_matches_eip712_domain(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+    ) {
+        let type_hash = keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+        );
+        let expected = keccak256(
+            (
+                type_hash,
+                keccak256(Eip712::NAME),
+                keccak256(Eip712::VERSION),
+                U256::from(block::chainid()),
+                contract.address(),
+            )
+                .abi_encode(),
+        );
+
+        assert_eq!(expected, contract.sender(alice).domain_separator());
+    }
+
+    #[motsu::test]
+    fn permit_sets_allowance(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash = permit_hash(&contract, owner, bob, U256::ZERO, U256::MAX);
+        let (v, r, s) = sign(&signer, hash);
+
+        contract
+            .sender(bob)
+            .permit(owner, bob, TOKEN_ID, AMOUNT, U256::MAX, v, r, s)
+            .expect("should accept the owner's signature");
+
+        assert_eq!(
+            AMOUNT,
+            contract.sender(bob).allowance(owner, bob, TOKEN_ID)
+        );
+        assert_eq!(U256::ONE, contract.sender(bob).nonces(owner));
+        contract.assert_emitted(&Approval {
+            owner,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: AMOUNT,
+        });
+    }
+
+    #[motsu::test]
+    fn permit_reverts_when_expired_deadline(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash =
+            permit_hash(&contract, owner, bob, U256::ZERO, EXPIRED_DEADLINE);
+        let (v, r, s) = sign(&signer, hash);
+
+        let err = contract
+            .sender(bob)
+            .permit(owner, bob, TOKEN_ID, AMOUNT, EXPIRED_DEADLINE, v, r, s)
+            .expect_err("should not accept an expired signature");
+
+        assert!(matches!(
+            err,
+            Error::ExpiredSignature(ERC6909ExpiredSignature { deadline })
+                if deadline == EXPIRED_DEADLINE
+        ));
+        assert_eq!(U256::ZERO, contract.sender(bob).nonces(owner));
+    }
+
+    #[motsu::test]
+    fn permit_reverts_when_invalid_signer(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let hash = permit_hash(&contract, alice, bob, U256::ZERO, U256::MAX);
+        let (v, r, s) = sign(&signer, hash);
+
+        let err = contract
+            .sender(bob)
+            .permit(alice, bob, TOKEN_ID, AMOUNT, U256::MAX, v, r, s)
+            .expect_err("should not accept a signature of another account");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSigner(ERC6909InvalidSigner { signer: recovered, owner })
+                if recovered == signer.address() && owner == alice
+        ));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(bob).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn permit_reverts_when_replayed(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash = permit_hash(&contract, owner, bob, U256::ZERO, U256::MAX);
+        let (v, r, s) = sign(&signer, hash);
+
+        contract
+            .sender(bob)
+            .permit(owner, bob, TOKEN_ID, AMOUNT, U256::MAX, v, r, s)
+            .expect("should accept the owner's signature");
+
+        let err = contract
+            .sender(bob)
+            .permit(owner, bob, TOKEN_ID, AMOUNT, U256::MAX, v, r, s)
+            .expect_err("should not accept a used nonce");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSigner(ERC6909InvalidSigner { owner: o, .. })
+                if o == owner
+        ));
+    }
+
+    /// Returns the EIP-712 digest of an `OperatorApproval` of `contract`.
+    fn operator_approval_hash(
+        contract: &Contract<Erc6909PermitExample>,
+        owner: Address,
+        operator: Address,
+        nonce: U256,
+        deadline: U256,
+    ) -> B256 {
+        let struct_hash = keccak256(OperatorApprovalHashTuple::abi_encode(&(
+            OPERATOR_APPROVAL_TYPEHASH,
+            owner,
+            operator,
+            true,
+            nonce,
+            deadline,
+        )));
+        let domain_separator = contract.sender(owner).domain_separator();
+        to_typed_data_hash(&domain_separator, &struct_hash)
+    }
+
+    #[storage]
+    struct Wallet {
+        valid: StorageBool,
+    }
+
+    #[public]
+    impl Wallet {
+        #[selector(name = "isValidSignature")]
+        fn is_valid_signature(
+            &self,
+            _hash: B256,
+            _signature: Bytes,
+        ) -> FixedBytes<4> {
+            if self.valid.get() {
+                signature_checker::ERC1271_MAGIC_VALUE.into()
+            } else {
+                FixedBytes::ZERO
+            }
+        }
+    }
+
+    unsafe impl TopLevelStorage for Wallet {}
+
+    #[motsu::test]
+    fn set_operator_by_sig_sets_operator(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash = operator_approval_hash(
+            &contract,
+            owner,
+            bob,
+            U256::ZERO,
+            U256::MAX,
+        );
+        let signature = signer.sign_hash_sync(&hash).expect("should sign");
+
+        let result = contract
+            .sender(bob)
+            .set_operator_by_sig(
+                owner,
+                bob,
+                true,
+                U256::MAX,
+                signature.as_bytes().to_vec().into(),
+            )
+            .expect("should accept the owner's signature");
+
+        assert!(result);
+        assert!(contract.sender(bob).is_operator(owner, bob));
+        assert_eq!(U256::ONE, contract.sender(bob).nonces(owner));
+        contract.assert_emitted(&erc6909::OperatorSet {
+            owner,
+            spender: bob,
+            approved: true,
+        });
+    }
+
+    #[motsu::test]
+    fn set_operator_by_sig_reverts_when_expired_deadline(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash = operator_approval_hash(
+            &contract,
+            owner,
+            bob,
+            U256::ZERO,
+            EXPIRED_DEADLINE,
+        );
+        let signature = signer.sign_hash_sync(&hash).expect("should sign");
+
+        let err = contract
+            .sender(bob)
+            .set_operator_by_sig(
+                owner,
+                bob,
+                true,
+                EXPIRED_DEADLINE,
+                signature.as_bytes().to_vec().into(),
+            )
+            .expect_err("should not accept an expired signature");
+
+        assert!(matches!(
+            err,
+            Error::ExpiredSignature(ERC6909ExpiredSignature { deadline })
+                if deadline == EXPIRED_DEADLINE
+        ));
+        assert!(!contract.sender(bob).is_operator(owner, bob));
+    }
+
+    #[motsu::test]
+    fn set_operator_by_sig_reverts_when_replayed(
+        contract: Contract<Erc6909PermitExample>,
+        bob: Address,
+    ) {
+        let signer = PrivateKeySigner::random();
+        let owner = signer.address();
+        let hash = operator_approval_hash(
+            &contract,
+            owner,
+            bob,
+            U256::ZERO,
+            U256::MAX,
+        );
+        let signature: Bytes = signer
+            .sign_hash_sync(&hash)
+            .expect("should sign")
+            .as_bytes()
+            .to_vec()
+            .into();
+
+        contract
+            .sender(bob)
+            .set_operator_by_sig(owner, bob, true, U256::MAX, signature.clone())
+            .expect("should accept the owner's signature");
+
+        let err = contract
+            .sender(bob)
+            .set_operator_by_sig(owner, bob, true, U256::MAX, signature)
+            .expect_err("should not accept a used nonce");
+
+        assert!(matches!(
+            err,
+            Error::InvalidOperatorSignature(ERC6909InvalidOperatorSignature {
+                owner: o,
+                operator,
+            }) if o == owner && operator == bob
+        ));
+    }
+
+    #[motsu::test]
+    fn set_operator_by_sig_accepts_erc1271_signature(
+        contract: Contract<Erc6909PermitExample>,
+        wallet: Contract<Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        wallet.init(alice, |wallet| wallet.valid.set(true));
+
+        contract
+            .sender(bob)
+            .set_operator_by_sig(
+                wallet.address(),
+                bob,
+                true,
+                U256::MAX,
+                vec![0u8; 65].into(),
+            )
+            .expect("should accept the wallet's signature");
+
+        assert!(contract.sender(bob).is_operator(wallet.address(), bob));
+        assert_eq!(U256::ONE, contract.sender(bob).nonces(wallet.address()));
+    }
+
+    #[motsu::test]
+    fn set_operator_by_sig_reverts_when_erc1271_rejects(
+        contract: Contract<Erc6909PermitExample>,
+        wallet: Contract<Wallet>,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(bob)
+            .set_operator_by_sig(
+                wallet.address(),
+                bob,
+                true,
+                U256::MAX,
+                vec![0u8; 65].into(),
+            )
+            .expect_err("should not accept a rejected signature");
+
+        assert!(matches!(
+            err,
+            Error::InvalidOperatorSignature(ERC6909InvalidOperatorSignature {
+                owner,
+                operator,
+            }) if owner == wallet.address() && operator == bob
+        ));
+        assert!(!contract.sender(bob).is_operator(wallet.address(), bob));
+    }
+
+    #[motsu::test]
+    fn set_operator_by_sig_reverts_for_malformed_signature(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(bob)
+            .set_operator_by_sig(
+                alice,
+                bob,
+                true,
+                U256::MAX,
+                vec![0u8; 64].into(),
+            )
+            .expect_err("should not accept a malformed signature");
+        assert!(matches!(
+            err,
+            Error::InvalidOperatorSignature(ERC6909InvalidOperatorSignature {
+                owner,
+                operator,
+            }) if owner == alice && operator == bob
+        ));
+        assert!(!contract.sender(alice).is_operator(alice, bob));
+    }
+
+    #[motsu::test]
+    fn interface_ids() {
+        let actual = <Erc6909PermitExample as IErc6909Permit>::interface_id();
+        let expected: FixedBytes<4> = 0x9324aef5_u32.into();
+        assert_eq!(actual, expected);
+
+        let actual =
+            <Erc6909PermitExample as IErc6909OperatorSignature>::interface_id();
+        let expected: FixedBytes<4> = 0x16c9230d_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
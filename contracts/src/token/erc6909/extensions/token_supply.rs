@@ -1,201 +1,718 @@
-#![no_std]
-extern crate alloc;
-
+//! Extension of ERC-6909 that adds tracking of total supply per token id.
+//!
+//! Every balance change goes through the [`Erc6909Update::_update`] hook of
+//! [`Erc6909Supply`], so the total supply of a token id always equals the sum
+//! of its balances, the total supply of all ids the sum of the per-id
+//! supplies, and the holders count of an id the number of accounts with a
+//! non-zero balance of it. A burn that would take a supply below zero breaks
+//! these invariants, and reverts instead of being clamped.
+//!
+//! NOTE: This contract implies a global limit of 2**256 - 1 to the number
+//! of tokens that can be minted, across all ids.
 use alloc::{vec, vec::Vec};
+use core::ops::{Deref, DerefMut};
 
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
-    prelude::{storage, HostAccess, StorageType},
-    storage::{StorageMap, StorageUint},
+    msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
 };
 
-
-// === change these imports to point at the core ERC-6909 and its traits ===
-use super::super::{
-    erc6909::Erc6909,
-    error::Error,
-    traits::{IErc6909, IErc6909Mintable, IErc6909Burnable, IErc6909Supply},
+use crate::{
+    token::erc6909::{
+        erc6909::{Erc6909, Erc6909Update},
+        error::Error,
+        traits::{
+            IErc6909, IErc6909Allowance, IErc6909Batch, IErc6909Burnable,
+            IErc6909Mintable, IErc6909Supply,
+        },
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        math::storage::{AddAssignChecked, SubAssignChecked},
+    },
 };
 
-/// ERC-6909 + per-ID total-supply extension.
+/// State of an [`Erc6909Supply`] contract.
 #[storage]
 pub struct Erc6909Supply {
-    /// The core ERC-6909 logic
-    pub base: Erc6909,
-    /// Tracks minted minus burned for each token ID
-    total_supplies: StorageMap<U256, StorageUint<256, 4>>,
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to total supply.
+    pub(crate) total_supplies: StorageMap<U256, StorageU256>,
+    /// Total supply of all token ids.
+    pub(crate) total_supply_all: StorageU256,
+    /// Mapping from token id to the number of accounts holding it.
+    pub(crate) holders_counts: StorageMap<U256, StorageU256>,
+}
+
+impl Deref for Erc6909Supply {
+    type Target = Erc6909;
+
+    fn deref(&self) -> &Self::Target {
+        &self.erc6909
+    }
+}
+
+impl DerefMut for Erc6909Supply {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.erc6909
+    }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909Supply: just read out our `total_supplies` map
-// --------------------------------------------------------------------------
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909Supply {}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Batch<Error = Error>,
+    IErc6909Allowance<Error = Error>,
+    IErc6909Supply,
+    IErc165
+)]
+impl Erc6909Supply {}
+
+#[public]
 impl IErc6909Supply for Erc6909Supply {
     fn total_supply(&self, id: U256) -> U256 {
         self.total_supplies.get(id)
     }
+
+    fn total_supply_all(&self) -> U256 {
+        self.total_supply_all.get()
+    }
+
+    fn holders_count(&self, id: U256) -> U256 {
+        self.holders_counts.get(id)
+    }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909: forward every core method to `base`
-// --------------------------------------------------------------------------
+#[public]
 impl IErc6909 for Erc6909Supply {
+    type Error = Error;
+
     fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.base.balance_of(owner, id)
+        self.erc6909.balance_of(owner, id)
     }
 
     fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.base.allowance(owner, spender, id)
+        self.erc6909.allowance(owner, spender, id)
     }
 
-    fn is_operator(&self, owner: Address, operator: Address) -> bool {
-        self.base.is_operator(owner, operator)
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Error> {
+        self._transfer(msg::sender(), receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Error> {
+        self._transfer_from(msg::sender(), sender, receiver, id, amount)?;
+        Ok(true)
     }
 
     fn approve(
         &mut self,
-        caller: Address,
         spender: Address,
         id: U256,
         amount: U256,
     ) -> Result<bool, Error> {
-        self.base.approve(caller, spender, id, amount)
+        self.erc6909.approve(spender, id, amount)
     }
 
     fn set_operator(
         &mut self,
-        caller: Address,
-        operator: Address,
+        spender: Address,
         approved: bool,
     ) -> Result<bool, Error> {
-        self.base.set_operator(caller, operator, approved)
+        self.erc6909.set_operator(spender, approved)
     }
+}
 
-    fn transfer(
+#[public]
+impl IErc6909Batch for Erc6909Supply {
+    type Error = Error;
+
+    fn balance_of_batch(
+        &self,
+        owners: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Error> {
+        self.erc6909.balance_of_batch(owners, ids)
+    }
+
+    fn batch_transfer_from(
         &mut self,
-        caller: Address,
-        to: Address,
+        sender: Address,
+        receiver: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, Error> {
+        self._batch_transfer_from(
+            msg::sender(),
+            sender,
+            receiver,
+            ids,
+            amounts,
+        )?;
+        Ok(true)
+    }
+}
+
+#[public]
+impl IErc6909Allowance for Erc6909Supply {
+    type Error = Error;
+
+    fn increase_allowance(
+        &mut self,
+        spender: Address,
         id: U256,
-        amount: U256,
+        added_value: U256,
     ) -> Result<bool, Error> {
-        self.base.transfer(caller, to, id, amount)
+        self.erc6909.increase_allowance(spender, id, added_value)
     }
 
-    fn transfer_from(
+    fn decrease_allowance(
         &mut self,
-        caller: Address,
-        from: Address,
-        to: Address,
+        spender: Address,
         id: U256,
-        amount: U256,
+        subtracted_value: U256,
     ) -> Result<bool, Error> {
-        self.base.transfer_from(caller, from, to, id, amount)
+        self.erc6909.decrease_allowance(spender, id, subtracted_value)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Supply {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Supply>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
     }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909Mintable: bump our total_supplies then mint balances
-// --------------------------------------------------------------------------
 impl IErc6909Mintable for Erc6909Supply {
+    type Error = Error;
+
     fn mint(
         &mut self,
-        caller: Address,
         to: Address,
         id: U256,
         amount: U256,
     ) -> Result<(), Error> {
-        // 1) update total_supplies[id] += amount
-        let old = self.total_supplies.get(id);
-        let new = old.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
-        self.total_supplies.insert(id, new);
-        // 2) mint the balance
-        self.base.mint(caller, to, id, amount)
+        self._mint(to, id, amount)
     }
 }
 
-// --------------------------------------------------------------------------
-// IErc6909Burnable: burn balances then decrement total_supplies
-// --------------------------------------------------------------------------
 impl IErc6909Burnable for Erc6909Supply {
+    type Error = Error;
+
     fn burn(
         &mut self,
-        caller: Address,
         from: Address,
         id: U256,
         amount: U256,
     ) -> Result<(), Error> {
-        // 1) burn the balance
-        self.base.burn(caller, from, id, amount)?;
-        // 2) decrement total_supplies[id]
-        let old = self.total_supplies.get(id);
-        // you may want to guard underflow here; core already checks balances
-        let new = old.checked_sub(amount).unwrap_or_default();
-        self.total_supplies.insert(id, new);
+        self._burn_from(msg::sender(), from, id, amount)
+    }
+}
+
+impl Erc6909Update for Erc6909Supply {
+    type Error = Error;
+
+    fn erc6909(&self) -> &Erc6909 {
+        &self.erc6909
+    }
+
+    fn erc6909_mut(&mut self) -> &mut Erc6909 {
+        &mut self.erc6909
+    }
+
+    /// Extended version of [`Erc6909::_update`] that updates the supply of
+    /// tokens and the number of their holders.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance and/or supply exceeds [`U256::MAX`], may happen
+    ///   during a mint.
+    /// * If a burn takes the supply of tokens below zero, which means the
+    ///   balances and supplies are out of sync.
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let receiver_was_holder =
+            to.is_zero() || !self.erc6909.balance_of(to, id).is_zero();
+
+        self._update_supply(from, to, id, amount)?;
+
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        if !receiver_was_holder {
+            self.holders_counts.setter(id).add_assign_checked(
+                U256::ONE,
+                "should not exceed `U256::MAX` for `holders_count`",
+            );
+        }
+
+        if !from.is_zero() && self.erc6909.balance_of(from, id).is_zero() {
+            self.holders_counts.setter(id).sub_assign_checked(
+                U256::ONE,
+                "should not remove more holders than `holders_count`",
+            );
+        }
+
         Ok(())
     }
 }
 
+impl Erc6909Supply {
+    /// Version of [`Erc6909Update::_update`] that updates the supply of
+    /// tokens, but not the number of their holders.
+    ///
+    /// Meant for contracts that already count the holders of each id, e.g.
+    /// through [`super::enumerable::Erc6909Enumerable`], and report that
+    /// count from [`IErc6909Supply::holders_count`] instead. The holders
+    /// counts of this contract are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `from` doesn't have a balance of
+    ///   at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance and/or supply exceeds [`U256::MAX`], may happen
+    ///   during a mint.
+    /// * If a burn takes the supply of tokens below zero, which means the
+    ///   balances and supplies are out of sync.
+    pub fn _update_supply(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.erc6909._update(from, to, id, amount)?;
+
+        if from.is_zero() {
+            self.total_supplies.setter(id).add_assign_checked(
+                amount,
+                "should not exceed `U256::MAX` for `total_supply`",
+            );
+            self.total_supply_all.add_assign_checked(
+                amount,
+                "should not exceed `U256::MAX` for `total_supply_all`",
+            );
+        }
 
+        if to.is_zero() {
+            self.total_supplies.setter(id).sub_assign_checked(
+                amount,
+                "should not burn more than `total_supply`",
+            );
+            self.total_supply_all.sub_assign_checked(
+                amount,
+                "should not burn more than `total_supply_all`",
+            );
+        }
+
+        Ok(())
+    }
+}
 
-// ——————————————————————————————————————————————————————————————————————————
-// motsu-driven Unit tests
-// Run : cargo test -p openzeppelin-stylus --features stylus-test
-// ——————————————————————————————————————————————————————————————————————————
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use alloy_primitives::{Address, U256};
-    use stylus_sdk::testing::TestVM;
-    use motsu::prelude::*;
-
-    // Tell Motsu how to snapshot/rollback this pure-storage type
-    #[cfg_attr(feature = "token_supply", entrypoint)]
-    unsafe impl stylus_sdk::testing::TopLevelStorage for Erc6909Supply {}
+    use alloy_primitives::{Address, FixedBytes, U256};
+    use motsu::prelude::Contract;
 
-    /// Helper to get a fresh `Contract<Erc6909Supply>` and a random account
-    fn fresh() -> Contract<Erc6909Supply> {
-        Contract::<Erc6909Supply>::new(&TestVM::default())
-    }
+    use super::*;
+    use crate::token::erc6909::error::{
+        ERC6909InsufficientBalance, ERC6909InvalidReceiver,
+    };
 
     #[motsu::test]
-    fn initial_total_is_zero(mut c: Contract<Erc6909Supply>, owner: Address) {
+    fn initial_total_is_zero(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+    ) {
         let id = U256::from(1u64);
         // Nothing minted yet → total_supply must be zero
-        assert_eq!(c.total_supply(id), U256::ZERO);
+        assert_eq!(contract.sender(alice).total_supply(id), U256::ZERO);
     }
 
     #[motsu::test]
-    fn mint_increases_total(mut c: Contract<Erc6909Supply>, owner: Address) {
-        let id     = U256::from(7u64);
+    fn mint_increases_total(contract: Contract<Erc6909Supply>, alice: Address) {
+        let id = U256::from(7u64);
         let amount = U256::from(42u64);
 
-        // Mint `amount` to `owner`
-        c.mint(owner, owner, id, amount).motsu_unwrap();
+        // Mint `amount` to `alice`
+        contract.sender(alice).mint(alice, id, amount).expect("should mint");
 
         // Now the total supply for `id` should be exactly `amount`
-        assert_eq!(c.total_supply(id), amount);
+        assert_eq!(contract.sender(alice).total_supply(id), amount);
     }
 
     #[motsu::test]
-    fn burn_decreases_total(mut c: Contract<Erc6909Supply>, owner: Address) {
-        let id         = U256::from(10u64);
+    fn burn_decreases_total(contract: Contract<Erc6909Supply>, alice: Address) {
+        let id = U256::from(10u64);
         let minted_amt = U256::from(50u64);
-        let burn_amt   = U256::from(15u64);
+        let burn_amt = U256::from(15u64);
 
         // Mint then burn
-        c.mint(owner, owner, id, minted_amt).motsu_unwrap();
-        c.burn(owner, owner, id, burn_amt).motsu_unwrap();
+        contract
+            .sender(alice)
+            .mint(alice, id, minted_amt)
+            .expect("should mint");
+        contract.sender(alice).burn(alice, id, burn_amt).expect("should burn");
 
         // Remaining supply = minted_amt - burn_amt
-        assert_eq!(c.total_supply(id), minted_amt - burn_amt);
+        assert_eq!(
+            contract.sender(alice).total_supply(id),
+            minted_amt - burn_amt
+        );
+    }
+
+    #[motsu::test]
+    fn transfers_keep_total_equal_to_balances(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(3u64);
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10u64))
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::from(4u64))
+            .expect("should transfer Alice's tokens to Bob");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as Alice's operator");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, id, U256::from(1u64))
+            .expect("should transfer Alice's tokens as operator");
+        contract
+            .sender(bob)
+            .burn(bob, id, U256::from(2u64))
+            .expect("should burn Bob's tokens");
+
+        let alice_balance = contract.sender(alice).balance_of(alice, id);
+        let bob_balance = contract.sender(alice).balance_of(bob, id);
+        assert_eq!(alice_balance, U256::from(5u64));
+        assert_eq!(bob_balance, U256::from(3u64));
+        assert_eq!(
+            contract.sender(alice).total_supply(id),
+            alice_balance + bob_balance
+        );
+    }
+
+    #[motsu::test]
+    fn authorized_burns_decrease_total(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        let id = U256::from(5u64);
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10u64))
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("should set Bob as Alice's operator");
+        contract
+            .sender(alice)
+            .approve(charlie, id, U256::from(2u64))
+            .expect("should approve Charlie");
+
+        contract
+            .sender(bob)
+            .burn(alice, id, U256::from(3u64))
+            .expect("should burn Alice's tokens as operator");
+        contract
+            .sender(charlie)
+            .burn(alice, id, U256::from(2u64))
+            .expect("should burn Alice's tokens with allowance");
+        contract
+            .sender(charlie)
+            .burn(alice, id, U256::ONE)
+            .expect_err("should not burn without allowance left");
+
+        let balance = contract.sender(alice).balance_of(alice, id);
+        assert_eq!(balance, U256::from(5u64));
+        assert_eq!(contract.sender(alice).total_supply(id), balance);
     }
 
     #[motsu::test]
-    fn burn_without_mint_reverts(mut c: Contract<Erc6909Supply>, owner: Address) {
+    fn batches_update_each_total(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let ids = vec![U256::from(1u64), U256::from(2u64)];
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                ids.clone(),
+                vec![U256::from(10u64), U256::from(20u64)],
+            )
+            .expect("should mint a batch");
+        contract
+            .sender(alice)
+            .batch_transfer_from(
+                alice,
+                bob,
+                ids.clone(),
+                vec![U256::from(3u64), U256::from(4u64)],
+            )
+            .expect("should transfer a batch");
+        contract
+            .sender(alice)
+            ._burn_batch(
+                bob,
+                ids.clone(),
+                vec![U256::from(1u64), U256::from(2u64)],
+            )
+            .expect("should burn a batch");
+
+        assert_eq!(
+            contract.sender(alice).total_supply(ids[0]),
+            U256::from(9u64)
+        );
+        assert_eq!(
+            contract.sender(alice).total_supply(ids[1]),
+            U256::from(18u64)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_without_mint_reverts(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+    ) {
         let id = U256::from(99u64);
 
         // Trying to burn an ID that was never minted should revert
-        c.burn(owner, owner, id, U256::ONE)
-            .motsu_unwrap_err();
+        let err = contract
+            .sender(alice)
+            .burn(alice, id, U256::ONE)
+            .expect_err("should not burn without balance");
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id: token_id,
+            }) if sender == alice
+                && balance.is_zero()
+                && needed == U256::ONE
+                && token_id == id
+        ));
     }
-}
 
+    #[motsu::test]
+    fn mint_reverts_for_zero_receiver(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .mint(Address::ZERO, U256::ONE, U256::ONE)
+            .expect_err("should not mint to the zero address");
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn total_supply_all_sums_every_id(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .mint(alice, U256::from(1u64), U256::from(10u64))
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .mint(bob, U256::from(2u64), U256::from(5u64))
+            .expect("should mint");
+        assert_eq!(
+            contract.sender(alice).total_supply_all(),
+            U256::from(15u64)
+        );
+
+        contract
+            .sender(alice)
+            .transfer(bob, U256::from(1u64), U256::from(4u64))
+            .expect("should transfer Alice's tokens to Bob");
+        assert_eq!(
+            contract.sender(alice).total_supply_all(),
+            U256::from(15u64)
+        );
+
+        contract
+            .sender(bob)
+            .burn(bob, U256::from(2u64), U256::from(5u64))
+            .expect("should burn Bob's tokens");
+        assert_eq!(
+            contract.sender(alice).total_supply_all(),
+            U256::from(10u64)
+        );
+    }
+
+    #[motsu::test]
+    fn holders_count_tracks_non_zero_balances(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = U256::from(4u64);
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ZERO);
+
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10u64))
+            .expect("should mint");
+        contract
+            .sender(alice)
+            .mint(alice, id, U256::from(10u64))
+            .expect("should mint again");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ONE);
+
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::ZERO)
+            .expect("should transfer zero tokens");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ONE);
+
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::from(5u64))
+            .expect("should transfer Alice's tokens to Bob");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::from(2u64));
+
+        contract
+            .sender(alice)
+            .transfer(alice, id, U256::from(15u64))
+            .expect("should transfer Alice's tokens to herself");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::from(2u64));
+
+        contract
+            .sender(alice)
+            .transfer(bob, id, U256::from(15u64))
+            .expect("should transfer the rest of Alice's tokens to Bob");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ONE);
+
+        contract
+            .sender(bob)
+            .burn(bob, id, U256::from(20u64))
+            .expect("should burn Bob's tokens");
+        assert_eq!(contract.sender(alice).holders_count(id), U256::ZERO);
+    }
+
+    #[motsu::test]
+    #[should_panic = "should not burn more than `total_supply`"]
+    fn burn_panics_on_supply_underflow(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+    ) {
+        let id = U256::from(8u64);
+        contract.init(alice, |contract| {
+            // Desynchronize the ledger from its supply, bypassing the hook.
+            contract
+                .erc6909
+                ._update(Address::ZERO, alice, id, U256::from(3u64))
+                .expect("should mint without updating the supply");
+        });
+
+        _ = contract.sender(alice).burn(alice, id, U256::from(3u64));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Supply as IErc6909Supply>::interface_id();
+        let expected: FixedBytes<4> = 0x3aca6153_u32.into();
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<Erc6909Supply>, alice: Address) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Supply as IErc6909Supply>::interface_id()
+        ));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Supply as IErc165>::interface_id()));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Supply as IErc6909>::interface_id()));
+
+        let fake_interface_id = 0x12345678_u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,914 @@
+//! Extension of ERC-6909 that wraps arbitrary ERC-20 tokens into claims.
+//!
+//! Anyone can deposit an amount of any ERC-20 token and receive the same
+//! amount of ERC-6909 claims of the type [`token_id`] of that token, i.e. its
+//! address cast to a number. Claims can be transferred and approved like any
+//! other ERC-6909 token, and burned to withdraw the deposited tokens, in the
+//! style of the claims of the Uniswap v4 `PoolManager`.
+//!
+//! The wrapper keeps track of the amount of each token it holds on behalf of
+//! the claim holders. Deposits of tokens that deliver less (or more) than the
+//! requested amount, e.g. fee-on-transfer tokens, are rejected with
+//! [`Error::UnexpectedDeposit`], since the claims would not be fully backed.
+//!
+//! WARNING: Any mechanism in which a wrapped token changes the balance of the
+//! wrapper without an explicit transfer, e.g. rebasing, may desynchronize the
+//! claims of that token and the wrapper's balance. See
+//! [`Erc6909Wrapping::_recover`] for recovering value accrued to the wrapper.
+//! A contract composing the wrapper must not mint the token ids of wrapped
+//! tokens in any other way, or their claims would not be fully backed.
+use alloy_primitives::{Address, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    contract,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::token::{
+    erc20::{
+        interface::Erc20Interface,
+        utils::{safe_erc20, ISafeErc20, SafeErc20},
+    },
+    erc6909::{
+        self,
+        erc6909::Erc6909Update,
+        error::{self, ERC6909InvalidReceiver, ERC6909InvalidSender},
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that the address is not a valid ERC-20 token.
+        ///
+        /// * `token` - Address of the invalid ERC-20 token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidUnderlying(address token);
+
+        /// Indicates that a deposit of `token` changed the balance of the
+        /// wrapper by another amount than the deposited one, e.g. because the
+        /// token charges a fee on transfer.
+        ///
+        /// * `token` - Address of the deposited ERC-20 token.
+        /// * `expected` - Amount of tokens deposited.
+        /// * `received` - Amount of tokens the wrapper received.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnexpectedDeposit(
+            address token,
+            uint256 expected,
+            uint256 received
+        );
+    }
+}
+
+/// An [`Erc6909Wrapper`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an error related to the current `balance` of `sender`.
+    /// Used in transfers.
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    /// Indicates a failure with the `spender`’s `allowance`. Used in
+    /// transfers.
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    /// Indicates a failure with the `approver` of a token to be approved.
+    /// Used in approvals.
+    InvalidApprover(error::ERC6909InvalidApprover),
+    /// Indicates a failure with the token `receiver`. Used in transfers.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates a failure with the token `sender`. Used in transfers.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates a failure with the `spender` to be approved. Used in
+    /// approvals.
+    InvalidSpender(error::ERC6909InvalidSpender),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    /// Indicates a failed `decrease_allowance` request.
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+    /// An operation with an ERC-20 token failed.
+    SafeErc20FailedOperation(safe_erc20::SafeErc20FailedOperation),
+    /// Indicates a failed [`ISafeErc20::safe_decrease_allowance`] request.
+    SafeErc20FailedDecreaseAllowance(
+        safe_erc20::SafeErc20FailedDecreaseAllowance,
+    ),
+    /// The token couldn't be wrapped.
+    InvalidUnderlying(ERC6909InvalidUnderlying),
+    /// A deposit changed the balance of the wrapper by another amount than
+    /// the deposited one.
+    UnexpectedDeposit(ERC6909UnexpectedDeposit),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl From<safe_erc20::Error> for Error {
+    fn from(value: safe_erc20::Error) -> Self {
+        match value {
+            safe_erc20::Error::SafeErc20FailedOperation(e) => {
+                Error::SafeErc20FailedOperation(e)
+            }
+            safe_erc20::Error::SafeErc20FailedDecreaseAllowance(e) => {
+                Error::SafeErc20FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns the id of the claims of `token`, i.e. its address cast to a
+/// number.
+///
+/// # Arguments
+///
+/// * `token` - Address of the wrapped ERC-20 token.
+#[must_use]
+pub fn token_id(token: Address) -> U256 {
+    U256::from_be_slice(token.as_slice())
+}
+
+/// State of an [`Erc6909Wrapper`] contract.
+#[storage]
+pub struct Erc6909Wrapper {
+    /// Mapping from token id to the amount of the wrapped token backing its
+    /// claims.
+    pub(crate) reserves: StorageMap<U256, StorageU256>,
+    /// [`SafeErc20`] contract.
+    safe_erc20: SafeErc20,
+}
+
+/// ERC-6909 Wrapper Standard Interface
+#[interface_id]
+pub trait IErc6909Wrapper {
+    /// The error type associated to the trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the amount of `token` backing its claims.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `token` - Address of the wrapped ERC-20 token.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn reserve(&self, token: Address) -> U256 {
+    ///     self.wrapper.reserve(token)
+    /// }
+    /// ```
+    #[must_use]
+    fn reserve(&self, token: Address) -> U256;
+
+    /// Allow a user to deposit `token` and mint the corresponding number of
+    /// claims of type [`token_id`] of `token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token to deposit.
+    /// * `account` - The account to mint claims to.
+    /// * `value` - The amount of tokens to deposit.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If the `msg::sender()`'s address is the
+    ///   `contract:address()`.
+    /// * [`Error::InvalidReceiver`] - If the `account` address is the
+    ///   `contract:address()` or [`Address::ZERO`].
+    /// * [`Error::InvalidUnderlying`] - If `token` is the `contract:address()`,
+    ///   or the external call for its balance fails.
+    /// * [`Error::SafeErc20FailedOperation`] - If caller lacks sufficient
+    ///   balance or hasn't approved enough tokens to the wrapper.
+    /// * [`Error::UnexpectedDeposit`] - If the balance of the wrapper changed
+    ///   by another amount than `value`.
+    fn deposit_for(
+        &mut self,
+        token: Address,
+        account: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error>;
+
+    /// Allow a user to burn a number of claims of type [`token_id`] of
+    /// `token` and withdraw the corresponding number of tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token to withdraw.
+    /// * `account` - The account to withdraw tokens to.
+    /// * `value` - The amount of tokens to withdraw.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If the `account`'s address is the
+    ///   `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If the caller doesn't have enough
+    ///   claims.
+    /// * [`Error::SafeErc20FailedOperation`] - If the transfer of `token`
+    ///   fails.
+    fn withdraw_to(
+        &mut self,
+        token: Address,
+        account: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error>;
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909Wrapper {}
+
+impl Erc6909Wrapper {
+    /// See [`IErc6909Wrapper::reserve`].
+    #[must_use]
+    pub fn reserve(&self, token: Address) -> U256 {
+        self.reserves.get(token_id(token))
+    }
+
+    /// Transfers `value` of `token` from `from` to the wrapper and adds it to
+    /// the reserve of `token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token to deposit.
+    /// * `from` - Account to transfer tokens from.
+    /// * `value` - The amount of tokens to deposit.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidUnderlying`] - If `token` is the `contract:address()`,
+    ///   or the external call for its balance fails.
+    /// * [`Error::SafeErc20FailedOperation`] - If the transfer of `token`
+    ///   fails.
+    /// * [`Error::UnexpectedDeposit`] - If the balance of the wrapper changed
+    ///   by another amount than `value`.
+    ///
+    /// # Panics
+    ///
+    /// * If the reserve of `token` exceeds [`U256::MAX`].
+    pub fn _deposit(
+        &mut self,
+        token: Address,
+        from: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        let contract_address = contract::address();
+        if token == contract_address {
+            return Err(ERC6909InvalidUnderlying { token }.into());
+        }
+
+        let balance_before = self._balance_of(token)?;
+        self.safe_erc20.safe_transfer_from(
+            token,
+            from,
+            contract_address,
+            value,
+        )?;
+        let received = self._balance_of(token)?.saturating_sub(balance_before);
+
+        if received != value {
+            return Err(ERC6909UnexpectedDeposit {
+                token,
+                expected: value,
+                received,
+            }
+            .into());
+        }
+
+        let id = token_id(token);
+        let reserve = self
+            .reserves
+            .get(id)
+            .checked_add(value)
+            .expect("should not exceed `U256::MAX` for `reserve`");
+        self.reserves.setter(id).set(reserve);
+
+        Ok(())
+    }
+
+    /// Removes `value` from the reserve of `token` and transfers it from the
+    /// wrapper to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token to withdraw.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - The amount of tokens to withdraw.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SafeErc20FailedOperation`] - If the transfer of `token`
+    ///   fails.
+    ///
+    /// # Panics
+    ///
+    /// * If the reserve of `token` is less than `value`.
+    pub fn _withdraw(
+        &mut self,
+        token: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        let id = token_id(token);
+        let reserve = self
+            .reserves
+            .get(id)
+            .checked_sub(value)
+            .expect("reserve should be greater than the withdrawn value");
+        self.reserves.setter(id).set(reserve);
+
+        self.safe_erc20.safe_transfer(token, to, value)?;
+
+        Ok(())
+    }
+
+    /// Adds the balance of `token` held by the wrapper beyond its reserve to
+    /// the reserve, and returns the added amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the wrapped ERC-20 token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidUnderlying`] - If the external call for the balance of
+    ///   `token` fails.
+    ///
+    /// # Panics
+    ///
+    /// * If the balance of `token` is less than its reserve.
+    pub fn _sync(&mut self, token: Address) -> Result<U256, Error> {
+        let balance = self._balance_of(token)?;
+        let id = token_id(token);
+        let value = balance
+            .checked_sub(self.reserves.get(id))
+            .expect("balance should be greater than the `reserve`");
+        self.reserves.setter(id).set(balance);
+        Ok(value)
+    }
+
+    /// Returns the balance of `token` held by the wrapper.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidUnderlying`] - If the external call for `balanceOf`
+    ///   fails.
+    fn _balance_of(&mut self, token: Address) -> Result<U256, Error> {
+        let contract_address = contract::address();
+        Erc20Interface::new(token)
+            .balance_of(Call::new_in(self), contract_address)
+            .map_err(|_| ERC6909InvalidUnderlying { token }.into())
+    }
+}
+
+/// ERC-6909 token minting claims of the wrapped ERC-20 tokens held by an
+/// [`Erc6909Wrapper`].
+///
+/// Layered over the [`Erc6909Update`] hook, so the claims go through every
+/// other extension of the contract.
+pub trait Erc6909Wrapping: Erc6909Update
+where
+    Self::Error: From<Error>,
+{
+    /// Returns the [`Erc6909Wrapper`] contract holding the reserves.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn wrapper(&self) -> &Erc6909Wrapper;
+
+    /// Returns the [`Erc6909Wrapper`] contract holding the reserves.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    fn wrapper_mut(&mut self) -> &mut Erc6909Wrapper;
+
+    /// Deposits `value` of `token` from `sender` and mints the same amount of
+    /// claims of type [`token_id`] of `token` to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account depositing the tokens.
+    /// * `token` - Address of the ERC-20 token to deposit.
+    /// * `account` - The account to mint claims to.
+    /// * `value` - The amount of tokens to deposit.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `sender` is the `contract:address()`.
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`
+    ///   or [`Address::ZERO`].
+    /// * [`Error::InvalidUnderlying`] - If `token` is the `contract:address()`,
+    ///   or the external call for its balance fails.
+    /// * [`Error::SafeErc20FailedOperation`] - If `sender` lacks sufficient
+    ///   balance or hasn't approved enough tokens to the wrapper.
+    /// * [`Error::UnexpectedDeposit`] - If the balance of the wrapper changed
+    ///   by another amount than `value`.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _deposit_for(
+        &mut self,
+        sender: Address,
+        token: Address,
+        account: Address,
+        value: U256,
+    ) -> Result<(), Self::Error> {
+        let contract_address = contract::address();
+        if sender == contract_address {
+            return Err(
+                Error::InvalidSender(ERC6909InvalidSender { sender }).into()
+            );
+        }
+        if account == contract_address {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: account,
+            })
+            .into());
+        }
+
+        self.wrapper_mut()._deposit(token, sender, value)?;
+        self._mint(account, token_id(token), value)
+    }
+
+    /// Burns `value` claims of type [`token_id`] of `token` from `sender` and
+    /// withdraws the same amount of `token` to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account withdrawing the tokens.
+    /// * `token` - Address of the ERC-20 token to withdraw.
+    /// * `account` - The account to withdraw tokens to.
+    /// * `value` - The amount of tokens to withdraw.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `account` is the `contract:address()`.
+    /// * [`Error::InsufficientBalance`] - If `sender` doesn't have enough
+    ///   claims.
+    /// * [`Error::SafeErc20FailedOperation`] - If the transfer of `token`
+    ///   fails.
+    ///
+    /// # Events
+    ///
+    /// * [`crate::token::erc6909::Transfer`].
+    fn _withdraw_to(
+        &mut self,
+        sender: Address,
+        token: Address,
+        account: Address,
+        value: U256,
+    ) -> Result<(), Self::Error> {
+        if account == contract::address() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: account,
+            })
+            .into());
+        }
+
+        self._burn(sender, token_id(token), value)?;
+        self.wrapper_mut()._withdraw(token, account, value)?;
+        Ok(())
+    }
+
+    /// Mint claims to cover any `token` that would have been transferred to
+    /// the wrapper by mistake or acquired from rebasing mechanisms, and
+    /// returns the minted amount.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the wrapped ERC-20 token.
+    /// * `account` - The account to mint claims to.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidUnderlying`] - If the external call for the balance of
+    ///   `token` fails.
+    /// * [`Error::InvalidReceiver`] - If `account` is [`Address::ZERO`].
+    ///
+    /// # Panics
+    ///
+    /// * If the balance of `token` is less than its reserve.
+    fn _recover(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<U256, Self::Error> {
+        let value = self.wrapper_mut()._sync(token)?;
+        if value > U256::ZERO {
+            self._mint(account, token_id(token), value)?;
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, FixedBytes};
+    use motsu::prelude::*;
+    use stylus_sdk::msg;
+
+    use super::*;
+    use crate::token::{
+        erc20::{self, Erc20, IErc20},
+        erc6909::{erc6909::Erc6909, IErc6909},
+    };
+
+    #[storage]
+    struct ClaimsToken {
+        erc6909: Erc6909,
+        wrapper: Erc6909Wrapper,
+    }
+
+    #[public]
+    #[implements(IErc6909Wrapper<Error = Error>)]
+    impl ClaimsToken {
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.erc6909.balance_of(owner, id)
+        }
+
+        fn recover(
+            &mut self,
+            token: Address,
+            account: Address,
+        ) -> Result<U256, Error> {
+            self._recover(token, account)
+        }
+    }
+
+    #[public]
+    impl IErc6909Wrapper for ClaimsToken {
+        type Error = Error;
+
+        fn reserve(&self, token: Address) -> U256 {
+            self.wrapper.reserve(token)
+        }
+
+        fn deposit_for(
+            &mut self,
+            token: Address,
+            account: Address,
+            value: U256,
+        ) -> Result<bool, Error> {
+            self._deposit_for(msg::sender(), token, account, value)?;
+            Ok(true)
+        }
+
+        fn withdraw_to(
+            &mut self,
+            token: Address,
+            account: Address,
+            value: U256,
+        ) -> Result<bool, Error> {
+            self._withdraw_to(msg::sender(), token, account, value)?;
+            Ok(true)
+        }
+    }
+
+    unsafe impl TopLevelStorage for ClaimsToken {}
+
+    impl Erc6909Update for ClaimsToken {
+        type Error = Error;
+
+        fn erc6909(&self) -> &Erc6909 {
+            &self.erc6909
+        }
+
+        fn erc6909_mut(&mut self) -> &mut Erc6909 {
+            &mut self.erc6909
+        }
+
+        fn _update(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) -> Result<(), Error> {
+            Ok(self.erc6909._update(from, to, id, amount)?)
+        }
+    }
+
+    impl Erc6909Wrapping for ClaimsToken {
+        fn wrapper(&self) -> &Erc6909Wrapper {
+            &self.wrapper
+        }
+
+        fn wrapper_mut(&mut self) -> &mut Erc6909Wrapper {
+            &mut self.wrapper
+        }
+    }
+
+    /// ERC-20 token burning a tenth of every amount moved with
+    /// `transferFrom` from the receiver.
+    #[storage]
+    struct FeeOnTransferToken {
+        erc20: Erc20,
+    }
+
+    #[public]
+    impl FeeOnTransferToken {
+        fn balance_of(&self, account: Address) -> U256 {
+            self.erc20.balance_of(account)
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: Address,
+            to: Address,
+            value: U256,
+        ) -> Result<bool, erc20::Error> {
+            self.erc20.transfer_from(from, to, value)?;
+            self.erc20._burn(to, value / uint!(10_U256))?;
+            Ok(true)
+        }
+    }
+
+    unsafe impl TopLevelStorage for FeeOnTransferToken {}
+
+    const AMOUNT: U256 = uint!(100_U256);
+
+    fn fund(erc20: &Contract<Erc20>, owner: Address, spender: Address) {
+        erc20.sender(owner)._mint(owner, AMOUNT).motsu_expect("should mint");
+        erc20
+            .sender(owner)
+            .approve(spender, AMOUNT)
+            .motsu_expect("should approve");
+    }
+
+    #[motsu::test]
+    fn token_id_is_address() {
+        let token = Address::repeat_byte(0xab);
+        let id = token_id(token);
+        assert_eq!(&id.to_be_bytes::<32>()[12..], token.as_slice());
+        assert!(id < U256::from(1) << 160);
+    }
+
+    #[motsu::test]
+    fn deposit_for_mints_claims(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        fund(&erc20, alice, contract.address());
+
+        contract
+            .sender(alice)
+            .deposit_for(erc20.address(), bob, AMOUNT)
+            .motsu_expect("should deposit");
+
+        let id = token_id(erc20.address());
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(bob, id));
+        assert_eq!(AMOUNT, contract.sender(alice).reserve(erc20.address()));
+        assert_eq!(AMOUNT, erc20.sender(alice).balance_of(contract.address()));
+        assert_eq!(U256::ZERO, erc20.sender(alice).balance_of(alice));
+    }
+
+    #[motsu::test]
+    fn withdraw_to_burns_claims(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        fund(&erc20, alice, contract.address());
+        contract
+            .sender(alice)
+            .deposit_for(erc20.address(), alice, AMOUNT)
+            .motsu_expect("should deposit");
+
+        let value = uint!(40_U256);
+        contract
+            .sender(alice)
+            .withdraw_to(erc20.address(), bob, value)
+            .motsu_expect("should withdraw");
+
+        let id = token_id(erc20.address());
+        assert_eq!(
+            AMOUNT - value,
+            contract.sender(alice).balance_of(alice, id)
+        );
+        assert_eq!(
+            AMOUNT - value,
+            contract.sender(alice).reserve(erc20.address())
+        );
+        assert_eq!(value, erc20.sender(alice).balance_of(bob));
+    }
+
+    #[motsu::test]
+    fn withdraw_to_reverts_when_insufficient_claims(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        fund(&erc20, alice, contract.address());
+        contract
+            .sender(alice)
+            .deposit_for(erc20.address(), alice, AMOUNT)
+            .motsu_expect("should deposit");
+
+        let err = contract
+            .sender(bob)
+            .withdraw_to(erc20.address(), bob, AMOUNT)
+            .motsu_expect_err("should return Error::InsufficientBalance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(error::ERC6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id,
+            }) if sender == bob
+                && balance.is_zero()
+                && needed == AMOUNT
+                && id == token_id(erc20.address())
+        ));
+    }
+
+    #[motsu::test]
+    fn deposit_for_reverts_when_invalid_receiver(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+    ) {
+        let invalid_receiver = contract.address();
+        fund(&erc20, alice, contract.address());
+
+        let err = contract
+            .sender(alice)
+            .deposit_for(erc20.address(), invalid_receiver, AMOUNT)
+            .motsu_expect_err("should return Error::InvalidReceiver");
+
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver == invalid_receiver
+        ));
+    }
+
+    #[motsu::test]
+    fn deposit_for_reverts_when_wrapping_itself(
+        contract: Contract<ClaimsToken>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .deposit_for(contract.address(), alice, AMOUNT)
+            .motsu_expect_err("should return Error::InvalidUnderlying");
+
+        assert!(matches!(
+            err,
+            Error::InvalidUnderlying(ERC6909InvalidUnderlying { token })
+                if token == contract.address()
+        ));
+    }
+
+    #[motsu::test]
+    fn deposit_for_reverts_when_insufficient_allowance(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+    ) {
+        erc20.sender(alice)._mint(alice, AMOUNT).motsu_expect("should mint");
+
+        let err = contract
+            .sender(alice)
+            .deposit_for(erc20.address(), alice, AMOUNT)
+            .motsu_expect_err("should return Error::SafeErc20");
+
+        assert!(matches!(
+            err,
+            Error::SafeErc20FailedOperation(
+                safe_erc20::SafeErc20FailedOperation { token }
+            ) if token == erc20.address()
+        ));
+    }
+
+    #[motsu::test]
+    fn deposit_for_reverts_on_fee_on_transfer(
+        contract: Contract<ClaimsToken>,
+        fee_token: Contract<FeeOnTransferToken>,
+        alice: Address,
+    ) {
+        fee_token.init(alice, |token| {
+            token.erc20._mint(alice, AMOUNT).expect("should mint");
+            token
+                .erc20
+                .approve(contract.address(), AMOUNT)
+                .expect("should approve");
+        });
+
+        let err = contract
+            .sender(alice)
+            .deposit_for(fee_token.address(), alice, AMOUNT)
+            .motsu_expect_err("should return Error::UnexpectedDeposit");
+
+        assert!(matches!(
+            err,
+            Error::UnexpectedDeposit(ERC6909UnexpectedDeposit {
+                token,
+                expected,
+                received,
+            }) if token == fee_token.address()
+                && expected == AMOUNT
+                && received == uint!(90_U256)
+        ));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).reserve(fee_token.address())
+        );
+    }
+
+    #[motsu::test]
+    fn recover_mints_tokens_sent_by_mistake(
+        contract: Contract<ClaimsToken>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        fund(&erc20, alice, contract.address());
+        let value = uint!(30_U256);
+        contract
+            .sender(alice)
+            .deposit_for(erc20.address(), alice, AMOUNT - value)
+            .motsu_expect("should deposit");
+        erc20
+            .sender(alice)
+            .transfer(contract.address(), value)
+            .motsu_expect("should transfer");
+
+        let recovered = contract
+            .sender(alice)
+            .recover(erc20.address(), bob)
+            .motsu_expect("should recover");
+
+        let id = token_id(erc20.address());
+        assert_eq!(value, recovered);
+        assert_eq!(value, contract.sender(alice).balance_of(bob, id));
+        assert_eq!(AMOUNT, contract.sender(alice).reserve(erc20.address()));
+
+        let recovered = contract
+            .sender(alice)
+            .recover(erc20.address(), bob)
+            .motsu_expect("should recover");
+        assert_eq!(U256::ZERO, recovered);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <ClaimsToken as IErc6909Wrapper>::interface_id();
+        let expected: FixedBytes<4> = 0x97396151_u32.into();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,28 @@
+//! Solidity Interfaces of the ERC-6909 token.
+pub use token::*;
+
+mod token {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    stylus_sdk::prelude::sol_interface! {
+        interface Erc6909Interface {
+            function balanceOf(address owner, uint256 id) external view returns (uint256);
+            function allowance(address owner, address spender, uint256 id) external view returns (uint256);
+            function isOperator(address owner, address spender) external view returns (bool);
+            function totalSupply(uint256 id) external view returns (uint256);
+            function transfer(address receiver, uint256 id, uint256 amount) external returns (bool);
+            function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool);
+            function approve(address spender, uint256 id, uint256 amount) external returns (bool);
+            function setOperator(address spender, bool approved) external returns (bool);
+        }
+
+        interface Erc6909FacadeHostInterface {
+            function facadeOf(uint256 id) external view returns (address);
+            function facadeTransfer(address from, address to, uint256 id, uint256 amount) external returns (bool);
+            function facadeTransferFrom(address spender, address from, address to, uint256 id, uint256 amount) external returns (bool);
+            function facadeApprove(address owner, address spender, uint256 id, uint256 amount) external returns (bool);
+        }
+    }
+}
@@ -1,24 +1,41 @@
-#![no_std]
-
+//! Implementation of the ERC-6909 multi-token standard.
+//!
+//! The core token lives in [`erc6909`], the interfaces in [`traits`] and the
+//! optional extensions in [`extensions`].
+pub mod composite_token;
 pub mod erc6909;
 pub mod error;
 pub mod extensions;
+pub mod interface;
 pub mod traits;
 
-
-// ─── Core exports ───────────────────────────────────────────────────────────
-pub use erc6909::Erc6909;
+pub use erc6909::{Approval, Erc6909, Erc6909Update, OperatorSet, Transfer};
 pub use error::Error;
-
-// ─── Extension *types* ──────────────────────────────────────────────────────
-pub use extensions::metadata_uri::Erc6909MetadataUri;
-pub use extensions::token_supply::Erc6909Supply;
-
-// ─── All of the ERC-6909 traits ──────────────────────────────────────────────
+pub use extensions::{
+    capped::Erc6909Capped,
+    collection_wrapper::{
+        Erc6909CollectionWrapping, IErc6909CollectionWrapper,
+    },
+    content_uri::{ContractURIUpdated, Erc6909ContentUri},
+    facade::{
+        Erc20Facade, Erc6909FacadeHosting, Erc6909Facades, FacadeSet,
+        IErc6909FacadeHost,
+    },
+    flash_loan::{
+        Erc6909FlashLending, Erc6909FlashLoans, IERC6909FlashBorrower,
+        IErc6909FlashLender,
+    },
+    metadata::Erc6909Metadata,
+    metadata_uri::{Erc6909MetadataUri, URI},
+    mintable::{Erc6909OwnableMintable, Erc6909RoleMintable, MINTER_ROLE},
+    pausable::{Erc6909Pausable, PAUSER_ROLE},
+    permit::{Erc6909Permit, IErc6909OperatorSignature, IErc6909Permit},
+    token_supply::Erc6909Supply,
+    wrapper::{Erc6909Wrapper, Erc6909Wrapping, IErc6909Wrapper},
+};
 pub use traits::{
-    IErc6909,
-    IErc6909Mintable,
-    IErc6909Burnable,
-    IErc6909Supply,
-    IErc6909MetadataUri,
+    IErc6909, IErc6909Allowance, IErc6909Batch, IErc6909Burnable,
+    IErc6909Capped, IErc6909ContentUri, IErc6909HolderEnumerable,
+    IErc6909Metadata, IErc6909MetadataUri, IErc6909Mintable,
+    IErc6909OwnerEnumerable, IErc6909Supply,
 };
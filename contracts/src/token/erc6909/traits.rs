@@ -1,94 +1,653 @@
-#![no_std]
-extern crate alloc;
+//! Interfaces of the ERC-6909 token standard and its extensions.
+//!
+//! Every state-changing function acts on behalf of `msg::sender()`, so the
+//! public ABI matches the signatures defined in [EIP-6909].
+//!
+//! [EIP-6909]: https://eips.ethereum.org/EIPS/eip-6909
+use alloc::{string::String, vec::Vec};
 
-use alloc::vec::Vec;
+use alloy_primitives::{Address, U256, U8};
+use openzeppelin_stylus_proc::interface_id;
 
-use alloy_primitives::{Address, U256};
+use crate::utils::introspection::erc165::IErc165;
 
-use super::error::Error;
+/// Required interface of an [`super::Erc6909`] compliant contract.
+///
+/// Its interface id is `0x0f632fb3`, as defined in [EIP-6909].
+///
+/// [EIP-6909]: https://eips.ethereum.org/EIPS/eip-6909
+#[interface_id]
+pub trait IErc6909: IErc165 {
+    /// The error type associated to this ERC-6909 trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
 
-/// Core ERC-6909 interface: single-ID multi-token.
-pub trait IErc6909 {
+    /// Returns the amount of tokens of type `id` owned by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `id` - Token id as a number.
     fn balance_of(&self, owner: Address, id: U256) -> U256;
+
+    /// Returns the amount of tokens of type `id` that `spender` is allowed to
+    /// spend on behalf of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
     fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256;
-    fn is_operator(&self, owner: Address, operator: Address) -> bool;
 
-    fn approve(
+    /// Returns true if `spender` is approved as an operator for `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `spender` - Account to be checked.
+    fn is_operator(&self, owner: Address, spender: Address) -> bool;
+
+    /// Moves an `amount` of tokens of type `id` from the caller's account to
+    /// `receiver`.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidReceiver`] - If `receiver` is
+    ///   [`Address::ZERO`].
+    /// * [`super::Error::InsufficientBalance`] - If the caller doesn't have a
+    ///   balance of at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`super::Transfer`].
+    fn transfer(
         &mut self,
-        caller: Address,
-        spender: Address,
+        receiver: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error>;
-
-    fn set_operator(
-        &mut self,
-        caller: Address,
-        operator: Address,
-        approved: bool,
-    ) -> Result<bool, Error>;
+    ) -> Result<bool, Self::Error>;
 
-    fn transfer(
+    /// Moves an `amount` of tokens of type `id` from `sender` to `receiver`.
+    ///
+    /// The caller must either be `sender`, an operator of `sender`, or have
+    /// an allowance of at least `amount` for `id`, which is then deducted
+    /// unless it is [`U256::MAX`].
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account to transfer tokens from.
+    /// * `receiver` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidSender`] - If `sender` is [`Address::ZERO`].
+    /// * [`super::Error::InvalidReceiver`] - If `receiver` is
+    ///   [`Address::ZERO`].
+    /// * [`super::Error::InsufficientAllowance`] - If the caller is neither
+    ///   `sender` nor its operator and not enough allowance is available.
+    /// * [`super::Error::InsufficientBalance`] - If `sender` doesn't have a
+    ///   balance of at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`super::Transfer`].
+    fn transfer_from(
         &mut self,
-        caller: Address,
-        to: Address,
+        sender: Address,
+        receiver: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error>;
+    ) -> Result<bool, Self::Error>;
 
-    fn transfer_from(
+    /// Sets an `amount` of tokens of type `id` as the allowance of `spender`
+    /// over the caller's tokens.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`super::Approval`].
+    fn approve(
         &mut self,
-        caller: Address,
-        from: Address,
-        to: Address,
+        spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error>;
+    ) -> Result<bool, Self::Error>;
+
+    /// Grants or revokes unlimited transfer permission of any token id to
+    /// `spender` for the caller's tokens, according to `approved`.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account to add to the set of authorized operators.
+    /// * `approved` - Flag that determines whether or not permission will be
+    ///   granted to `spender`.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`super::OperatorSet`].
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error>;
 }
 
-/// Mintable extension
+/// Mintable extension of the ERC-6909 standard.
+#[interface_id]
 pub trait IErc6909Mintable {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Creates an `amount` of tokens of type `id` and assigns them to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`super::Transfer`].
     fn mint(
         &mut self,
-        caller: Address,
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Self::Error>;
 }
 
-/// Burnable extension
+/// Burnable extension of the ERC-6909 standard.
+#[interface_id]
 pub trait IErc6909Burnable {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// The caller must be `from`, one of `from`'s operators, or have an
+    /// allowance of at least `amount`, which is then spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to burn tokens from.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InsufficientAllowance`] - If the caller is neither
+    ///   `from` nor an operator, and not enough allowance is available.
+    /// * [`super::Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`super::Error::InsufficientBalance`] - If `from` doesn't have a
+    ///   balance of at least `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`super::Transfer`].
     fn burn(
         &mut self,
-        caller: Address,
         from: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), Error>;
+    ) -> Result<(), Self::Error>;
+}
+
+/// Batch extension of the ERC-6909 standard, moving or querying several
+/// token types in a single call.
+#[interface_id]
+pub trait IErc6909Batch {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Batched version of [`IErc6909::balance_of`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owners` - All accounts of the tokens' owners.
+    /// * `ids` - All token identifiers.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidArrayLength`] - If the length of `owners` is
+    ///   not equal to the length of `ids`.
+    fn balance_of_batch(
+        &self,
+        owners: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Self::Error>;
+
+    /// Batched version of [`IErc6909::transfer_from`].
+    ///
+    /// Each pair of `ids` and `amounts` is moved as a separate transfer, and
+    /// the whole batch reverts if any of them fails.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account to transfer tokens from.
+    /// * `receiver` - Account of the recipient.
+    /// * `ids` - All token identifiers.
+    /// * `amounts` - All amounts to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidArrayLength`] - If the length of `ids` is not
+    ///   equal to the length of `amounts`.
+    /// * [`super::Error::InsufficientAllowance`] - If the caller is neither
+    ///   `sender` nor an operator, and not enough allowance is available for
+    ///   any of the `ids`.
+    /// * [`super::Error::InvalidSender`] - If `sender` is [`Address::ZERO`].
+    /// * [`super::Error::InvalidReceiver`] - If `receiver` is
+    ///   [`Address::ZERO`].
+    /// * [`super::Error::InsufficientBalance`] - If `sender` doesn't have a
+    ///   balance of at least the amount of any of the `ids`.
+    ///
+    /// # Events
+    ///
+    /// * [`super::Transfer`] - For each pair of `ids` and `amounts`.
+    fn batch_transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, Self::Error>;
+}
+
+/// Allowance extension of the ERC-6909 standard, adjusting an allowance
+/// relative to its current value.
+///
+/// Unlike [`IErc6909::approve`], these functions can't be front-run by a
+/// spender using the old allowance before the new one is set.
+#[interface_id]
+pub trait IErc6909Allowance {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Atomically increases by `added_value` the allowance of `spender` over
+    /// the caller's tokens of type `id`.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `added_value` - Amount by which the allowance is increased.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`super::Approval`].
+    ///
+    /// # Panics
+    ///
+    /// * If the allowance would exceed [`U256::MAX`].
+    fn increase_allowance(
+        &mut self,
+        spender: Address,
+        id: U256,
+        added_value: U256,
+    ) -> Result<bool, Self::Error>;
+
+    /// Atomically decreases by `subtracted_value` the allowance of `spender`
+    /// over the caller's tokens of type `id`.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `subtracted_value` - Amount by which the allowance is decreased.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::Error::FailedDecreaseAllowance`] - If the allowance is lower
+    ///   than `subtracted_value`.
+    /// * [`super::Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`super::Approval`].
+    fn decrease_allowance(
+        &mut self,
+        spender: Address,
+        id: U256,
+        subtracted_value: U256,
+    ) -> Result<bool, Self::Error>;
 }
 
-/// Supply-tracking extension
+/// Supply-tracking extension of the ERC-6909 standard.
+#[interface_id]
 pub trait IErc6909Supply {
+    /// Total amount of tokens with a given id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
     fn total_supply(&self, id: U256) -> U256;
+
+    /// Total amount of tokens, summed over every id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "totalSupply")]
+    fn total_supply_all(&self) -> U256;
+
+    /// Number of accounts holding a non-zero balance of a given id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn holders_count(&self, id: U256) -> U256;
 }
 
-/// Metadata-URI extension
+/// Metadata extension of the ERC-6909 standard.
+#[interface_id]
+pub trait IErc6909Metadata {
+    /// Returns the name of token type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn name(&self, id: U256) -> String;
+
+    /// Returns the symbol of token type `id`, usually a shorter version of
+    /// the name.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn symbol(&self, id: U256) -> String;
+
+    /// Returns the number of decimals used to get a user-friendly
+    /// representation of amounts of token type `id`.
+    ///
+    /// NOTE: This information is only used for *display* purposes: in
+    /// no way it affects any of the arithmetic of the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn decimals(&self, id: U256) -> U8;
+}
+
+/// Content URI extension of the ERC-6909 standard.
+#[interface_id]
+pub trait IErc6909ContentUri {
+    /// Returns the URI of the contract-level metadata.
+    ///
+    /// NOTE: The implementation should use `#[selector(name = "contractURI")]`
+    /// to match Solidity's camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "contractURI")]
+    fn contract_uri(&self) -> String;
+
+    /// Returns the URI for token type `id`.
+    ///
+    /// If the `{id}` substring is present in the URI, it must be replaced by
+    /// clients with the actual token type ID.
+    ///
+    /// NOTE: The implementation should use `#[selector(name = "tokenURI")]` to
+    /// match Solidity's camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    #[selector(name = "tokenURI")]
+    fn token_uri(&self, id: U256) -> String;
+}
+
+/// Metadata-URI extension of the ERC-6909 standard, allowing per-id URIs to
+/// override the [`IErc6909ContentUri::token_uri`].
+#[interface_id]
 pub trait IErc6909MetadataUri {
-    fn token_uri(&self, id: U256) -> Vec<u8>;
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Sets `uri` as the URI of token type `id`.
+    ///
+    /// NOTE: The implementation should use `#[selector(name = "setTokenURI")]`
+    /// to match Solidity's camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `uri` - New URI of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::extensions::metadata_uri::Error::UnauthorizedAccount`] - If
+    ///   the caller is not the admin.
+    ///
+    /// # Events
+    ///
+    /// * [`super::extensions::metadata_uri::URI`].
+    #[selector(name = "setTokenURI")]
     fn set_token_uri(
         &mut self,
-        caller: Address,
         id: U256,
-        uri: Vec<u8>,
-    ) -> Result<bool, Error>;
+        uri: String,
+    ) -> Result<(), Self::Error>;
 }
 
-/// Enumerable extension trait for ERC-6909.
+/// Enumerable extension of the ERC-6909 standard.
+#[interface_id]
 pub trait IErc6909Enumerable {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
     /// Returns total unique IDs recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
     fn total_ids(&self) -> U256;
-    /// Returns the ID at `index`, or zero if out of bounds.
-    fn id_by_index(&self, index: U256) -> U256;
+
+    /// Returns the ID at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `index` - Index of the token id in the list of recorded ids.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::extensions::enumerable::Error::OutOfBoundsIndex`] - If
+    ///   `index` is not lower than [`Self::total_ids`].
+    fn id_by_index(&self, index: U256) -> Result<U256, Self::Error>;
+
+    /// Returns at most `limit` recorded ids, starting at `offset`. The result
+    /// is shorter than `limit` past the end of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `offset` - Index of the first token id to return.
+    /// * `limit` - Maximum number of token ids to return.
+    fn ids_range(&self, offset: U256, limit: U256) -> Vec<U256>;
+}
+
+/// Per-owner enumerable extension of the ERC-6909 standard, listing the token
+/// ids each account holds.
+#[interface_id]
+pub trait IErc6909OwnerEnumerable {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns every token id `owner` holds a non-zero balance of, in no
+    /// particular order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    fn ids_of_owner(&self, owner: Address) -> Vec<U256>;
+
+    /// Returns the token id held by `owner` at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `index` - Index of the token id in the list of ids held by `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::extensions::enumerable::Error::OutOfBoundsIndex`] - If
+    ///   `index` is not lower than [`Self::owner_count`].
+    fn id_of_owner_by_index(
+        &self,
+        owner: Address,
+        index: U256,
+    ) -> Result<U256, Self::Error>;
+
+    /// Returns the number of token ids `owner` holds a non-zero balance of.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    fn owner_count(&self, owner: Address) -> U256;
+}
+
+/// Per-id holder enumerable extension of the ERC-6909 standard, listing the
+/// accounts holding each token id.
+#[interface_id]
+pub trait IErc6909HolderEnumerable {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns every account holding a non-zero balance of token type `id`,
+    /// in no particular order.
+    ///
+    /// WARNING: This reads the whole list of holders, which can exceed the
+    /// block gas limit for widely held ids. Prefer
+    /// [`Self::holders_range`] from other contracts.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn holders_of(&self, id: U256) -> Vec<Address>;
+
+    /// Returns the holder of token type `id` at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `index` - Index of the holder in the list of holders of `id`.
+    ///
+    /// # Errors
+    ///
+    /// * [`super::extensions::enumerable::Error::OutOfBoundsIndex`] - If
+    ///   `index` is not lower than [`Self::holder_count`].
+    fn holder_by_index(
+        &self,
+        id: U256,
+        index: U256,
+    ) -> Result<Address, Self::Error>;
+
+    /// Returns the number of accounts holding a non-zero balance of token
+    /// type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn holder_count(&self, id: U256) -> U256;
+
+    /// Returns at most `limit` holders of token type `id`, starting at
+    /// `offset`. The result is shorter than `limit` past the end of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `offset` - Index of the first holder to return.
+    /// * `limit` - Maximum number of holders to return.
+    fn holders_range(
+        &self,
+        id: U256,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<Address>;
 }
 
+/// Capped extension of the ERC-6909 standard, limiting the total supply of
+/// each token type.
+#[interface_id]
+pub trait IErc6909Capped {
+    /// Returns the cap on the total supply of tokens of type `id`, or
+    /// [`U256::MAX`] if the token type is not capped.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn cap(&self, id: U256) -> U256;
+}
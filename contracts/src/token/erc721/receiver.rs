@@ -4,7 +4,9 @@
 #![cfg_attr(coverage_nightly, coverage(off))]
 use alloc::vec;
 
-use stylus_sdk::prelude::sol_interface;
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+use stylus_sdk::{abi::Bytes, prelude::sol_interface};
 
 sol_interface! {
     /// [`super::Erc721`] token receiver interface.
@@ -38,3 +40,43 @@ sol_interface! {
         ) external returns (bytes4);
     }
 }
+
+/// Interface for any contract that wants to support safe transfers from
+/// [`super::Erc721`] asset contracts.
+#[interface_id]
+pub trait IErc721Receiver {
+    /// The error type associated to the trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// This function is called whenever an [`super::Erc721`] `token_id` token
+    /// is transferred to this contract via
+    /// [`super::IErc721::safe_transfer_from`].
+    ///
+    /// It must return [`super::RECEIVER_FN_SELECTOR`] to confirm the token
+    /// transfer. If any other value is returned or the call reverts, the
+    /// transfer will be reverted.
+    ///
+    /// NOTE: The implementation should use
+    /// `#[selector(name = "onERC721Received")]` to match Solidity's
+    /// camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account of the operator.
+    /// * `from` - Account of the sender.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format.
+    ///
+    /// # Errors
+    ///
+    /// * If the implementation rejects the transfer.
+    #[selector(name = "onERC721Received")]
+    fn on_erc721_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<FixedBytes<4>, Self::Error>;
+}
@@ -0,0 +1,239 @@
+//! Signature verification helper that can be used instead of
+//! [`ecdsa::recover`] to seamlessly support both ECDSA signatures from
+//! externally owned accounts (EOAs) and ERC-1271 signatures from smart
+//! contract wallets like Argent and Safe Wallet.
+//!
+//! NOTE: Unlike ECDSA signatures, contract signatures are revocable, and the
+//! outcome of this function can thus change through time. It could return
+//! true at block N and false at block N+1 (or the opposite).
+use alloy_primitives::{Address, FixedBytes, B256};
+use stylus_sdk::{call::Call, prelude::*};
+
+use crate::utils::cryptography::ecdsa;
+
+/// Value returned by [`IERC1271::is_valid_signature`] when the signature is
+/// valid, i.e. its own function selector.
+pub const ERC1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Length of an ECDSA signature encoded as `r ‖ s ‖ v`.
+const ECDSA_SIGNATURE_LENGTH: usize = 65;
+
+pub use wallet::*;
+mod wallet {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the ERC-1271 standard signature validation method for
+        /// contracts, as defined in [ERC-1271].
+        ///
+        /// [ERC-1271]: https://eips.ethereum.org/EIPS/eip-1271
+        interface IERC1271 {
+            /// Should return whether the signature provided is valid for the
+            /// provided data.
+            ///
+            /// # Arguments
+            ///
+            /// * `hash` - Hash of the data to be signed.
+            /// * `signature` - Signature byte array associated with `hash`.
+            function isValidSignature(bytes32 hash, bytes calldata signature) external view returns (bytes4 magicValue);
+        }
+    }
+}
+
+/// Checks if a signature is valid for a given signer and data hash. If the
+/// signer has code, the signature is validated against it using ERC-1271,
+/// otherwise it's validated using [`ecdsa::recover`].
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `signer` - Account expected to have signed `hash`.
+/// * `hash` - Hash of the message.
+/// * `signature` - Signature of `hash`, encoded as `r ‖ s ‖ v` when `signer` is
+///   an EOA.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn is_valid_signature_now(
+    storage: &mut impl TopLevelStorage,
+    signer: Address,
+    hash: B256,
+    signature: &[u8],
+) -> bool {
+    if signer.has_code() {
+        return is_valid_erc1271_signature_now(
+            storage, signer, hash, signature,
+        );
+    }
+
+    if signature.len() != ECDSA_SIGNATURE_LENGTH {
+        return false;
+    }
+
+    let r = B256::from_slice(&signature[..32]);
+    let s = B256::from_slice(&signature[32..64]);
+    let v = signature[64];
+
+    ecdsa::recover(storage, hash, v, r, s)
+        .is_ok_and(|recovered| recovered == signer)
+}
+
+/// Checks if a signature is valid for a given signer and data hash. The
+/// signature is validated against the signer smart contract using ERC-1271.
+///
+/// NOTE: Unlike [`is_valid_signature_now`], this function won't fall back to
+/// ECDSA recovery when `signer` is an EOA, and will return false.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `signer` - Contract expected to have signed `hash`.
+/// * `hash` - Hash of the message.
+/// * `signature` - Signature of `hash`, in a format understood by `signer`.
+pub fn is_valid_erc1271_signature_now(
+    storage: &mut impl TopLevelStorage,
+    signer: Address,
+    hash: B256,
+    signature: &[u8],
+) -> bool {
+    if !signer.has_code() {
+        return false;
+    }
+
+    IERC1271::new(signer)
+        .is_valid_signature(
+            Call::new_in(storage),
+            hash,
+            signature.to_vec().into(),
+        )
+        .is_ok_and(|magic_value| {
+            magic_value == FixedBytes::from(ERC1271_MAGIC_VALUE)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, B256};
+    use motsu::prelude::Contract;
+    use stylus_sdk::{abi::Bytes, prelude::*, storage::StorageBool};
+
+    use super::*;
+
+    #[storage]
+    struct Verifier;
+
+    #[public]
+    impl Verifier {}
+
+    unsafe impl TopLevelStorage for Verifier {}
+
+    #[storage]
+    struct Wallet {
+        valid: StorageBool,
+    }
+
+    #[public]
+    impl Wallet {
+        #[selector(name = "isValidSignature")]
+        fn is_valid_signature(
+            &self,
+            _hash: B256,
+            _signature: Bytes,
+        ) -> FixedBytes<4> {
+            if self.valid.get() {
+                ERC1271_MAGIC_VALUE.into()
+            } else {
+                FixedBytes::from([0xff; 4])
+            }
+        }
+    }
+
+    unsafe impl TopLevelStorage for Wallet {}
+
+    #[motsu::test]
+    fn magic_value_is_selector() {
+        assert_eq!(
+            ERC1271_MAGIC_VALUE,
+            stylus_sdk::function_selector!(
+                "isValidSignature",
+                B256,
+                stylus_sdk::abi::Bytes
+            )
+        );
+    }
+
+    #[motsu::test]
+    fn rejects_malformed_eoa_signature(
+        contract: Contract<Verifier>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            assert!(!is_valid_signature_now(
+                contract,
+                alice,
+                B256::ZERO,
+                &[0u8; 64]
+            ));
+        });
+    }
+
+    #[motsu::test]
+    fn accepts_erc1271_magic_value(
+        contract: Contract<Verifier>,
+        wallet: Contract<Wallet>,
+        alice: Address,
+    ) {
+        wallet.init(alice, |wallet| wallet.valid.set(true));
+
+        contract.init(alice, |contract| {
+            assert!(is_valid_signature_now(
+                contract,
+                wallet.address(),
+                B256::ZERO,
+                &[0u8; 65]
+            ));
+            assert!(is_valid_erc1271_signature_now(
+                contract,
+                wallet.address(),
+                B256::ZERO,
+                &[]
+            ));
+        });
+    }
+
+    #[motsu::test]
+    fn rejects_wrong_erc1271_magic_value(
+        contract: Contract<Verifier>,
+        wallet: Contract<Wallet>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            assert!(!is_valid_signature_now(
+                contract,
+                wallet.address(),
+                B256::ZERO,
+                &[0u8; 65]
+            ));
+        });
+    }
+
+    #[motsu::test]
+    fn rejects_erc1271_check_for_eoa(
+        contract: Contract<Verifier>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            assert!(!is_valid_erc1271_signature_now(
+                contract,
+                alice,
+                B256::ZERO,
+                &[0u8; 65]
+            ));
+        });
+    }
+}
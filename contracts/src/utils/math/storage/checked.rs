@@ -19,3 +19,21 @@ where
         self.set(new_balance);
     }
 }
+
+/// Subtract value and assign the result to `self`, panicking on underflow.
+pub(crate) trait SubAssignChecked<T> {
+    /// Subtract `rhs` and assign the result to `self`, panicking on
+    /// underflow.
+    fn sub_assign_checked(&mut self, rhs: T, msg: &str);
+}
+
+impl<const B: usize, const L: usize> SubAssignChecked<Uint<B, L>>
+    for StorageUint<B, L>
+where
+    IntBitCount<B>: SupportedInt,
+{
+    fn sub_assign_checked(&mut self, rhs: Uint<B, L>, msg: &str) {
+        let new_balance = self.get().checked_sub(rhs).expect(msg);
+        self.set(new_balance);
+    }
+}
@@ -1,41 +1,54 @@
 use alloy_primitives::{Address, U256};
-// core + the three extensions
-use openzeppelin_stylus::token::erc6909::extensions::{
-    enumerable::Erc6909Enumerable, metadata_uri::Erc6909MetadataUri,
-    token_supply::Erc6909Supply,
+use motsu::prelude::Contract;
+// core + the three extensions, combined in a single contract
+use openzeppelin_stylus::{
+    access::control::IAccessControl,
+    token::erc6909::{
+        composite_token::MyToken,
+        traits::{
+            IErc6909ContentUri, IErc6909Enumerable, IErc6909Metadata,
+            IErc6909MetadataUri, IErc6909Mintable, IErc6909Supply,
+        },
+        MINTER_ROLE,
+    },
 };
-use openzeppelin_stylus::token::erc6909::traits::{
-    IErc6909Enumerable, IErc6909MetadataUri, IErc6909Mintable, IErc6909Supply,
-};
-use stylus_sdk::testing::TestVM;
 
-fn main() {
-    let vm = TestVM::default();
-    let owner = Address::new([0xAA; 20]);
+#[motsu::test]
+fn all_three_extensions(contract: Contract<MyToken>, alice: Address) {
     let id = U256::from(42u64);
     let amount = U256::from(100u64);
 
+    contract.sender(alice).constructor(
+        String::from("Token"),
+        String::from("TKN"),
+        String::from("https://example.com/contract.json"),
+        String::from("https://example.com/{id}.json"),
+        alice,
+    );
+    contract
+        .sender(alice)
+        .grant_role(MINTER_ROLE.into(), alice)
+        .expect("should grant Alice the minter role");
+
     // ── Supply extension ───────────────────────────────
-    let mut supply = Erc6909Supply::from(&vm);
-    supply.mint(owner, owner, id, amount).unwrap();
-    assert_eq!(supply.total_supply(id), amount);
-    println!(" ✔ total supply = {}", supply.total_supply(id));
+    contract
+        .sender(alice)
+        .mint(alice, id, amount)
+        .expect("should mint tokens for Alice");
+    assert_eq!(contract.sender(alice).total_supply(id), amount);
 
     // ── Metadata extension ────────────────────────────
-    let mut uri_ext = Erc6909MetadataUri::from(&vm);
-    let uri = b"https://example.com/42".to_vec();
-    uri_ext.set_token_uri(owner, id, uri.clone()).unwrap();
-    assert_eq!(uri_ext.token_uri(id), uri);
-    println!(" ✔ metadata URI = {:?}", uri_ext.token_uri(id));
+    let uri = String::from("https://example.com/42");
+    contract
+        .sender(alice)
+        .set_token_uri(id, uri.clone())
+        .expect("should set token URI");
+    assert_eq!(contract.sender(alice).token_uri(id), uri);
+    assert_eq!(contract.sender(alice).name(id), "Token");
+    assert_eq!(contract.sender(alice).symbol(id), "TKN");
 
     // ── Enumerable extension ──────────────────────────
-    let mut enum_ext = Erc6909Enumerable::from(&vm);
-    // record it (in a real contract you'd do this in your _mint hooks)
-    enum_ext._record_id(id);
-
-    assert_eq!(enum_ext.total_ids(), U256::ONE);
-    assert_eq!(enum_ext.id_by_index(U256::ZERO), id);
-    println!(" ✔ enumerable id[0] = {}", enum_ext.id_by_index(U256::ZERO));
-
-    println!("✅ all three ERC-6909 extensions example OK");
+    // minting recorded the id
+    assert_eq!(contract.sender(alice).total_ids(), U256::ONE);
+    assert_eq!(contract.sender(alice).id_by_index(U256::ZERO).unwrap(), id);
 }
@@ -1,29 +1,44 @@
 use alloy_primitives::{Address, U256};
-use motsu::prelude::*;
+use motsu::prelude::Contract;
 use openzeppelin_stylus::token::erc6909::{traits::*, Erc6909};
-use stylus_sdk::testing::TestVM;
 
 #[motsu::test]
-fn full_mint_transfer_burn_flow() {
-    let vm = TestVM::default();
-    let mut token = Erc6909::from(&vm);
-    let owner = Address::new([0xAA; 20]);
-    let recipient = Address::new([0xBB; 20]);
+fn full_mint_transfer_burn_flow(
+    contract: Contract<Erc6909>,
+    alice: Address,
+    bob: Address,
+) {
     let id = U256::from(7u64);
 
     // mint
-    token.mint(owner, owner, id, U256::from(100u64)).unwrap();
-    assert_eq!(token.balance_of(owner, id), U256::from(100u64));
+    contract
+        .sender(alice)
+        .mint(alice, id, U256::from(100u64))
+        .expect("should mint tokens for Alice");
+    assert_eq!(
+        contract.sender(alice).balance_of(alice, id),
+        U256::from(100u64)
+    );
 
     // approve + transfer_from
-    token.approve(owner, recipient, id, U256::from(30u64)).unwrap();
-    assert!(token
-        .transfer_from(recipient, owner, recipient, id, U256::from(20u64))
-        .unwrap());
-    assert_eq!(token.balance_of(recipient, id), U256::from(20u64));
-    assert_eq!(token.allowance(owner, recipient, id), U256::from(10u64));
+    contract
+        .sender(alice)
+        .approve(bob, id, U256::from(30u64))
+        .expect("should approve Bob");
+    assert!(contract
+        .sender(bob)
+        .transfer_from(alice, bob, id, U256::from(20u64))
+        .expect("should transfer Alice's tokens to Bob"));
+    assert_eq!(contract.sender(alice).balance_of(bob, id), U256::from(20u64));
+    assert_eq!(
+        contract.sender(alice).allowance(alice, bob, id),
+        U256::from(10u64)
+    );
 
     // burn
-    token.burn(owner, owner, id, U256::from(10u64)).unwrap();
-    assert_eq!(token.balance_of(owner, id), U256::from(70u64));
+    contract
+        .sender(alice)
+        .burn(alice, id, U256::from(10u64))
+        .expect("should burn Alice's tokens");
+    assert_eq!(contract.sender(alice).balance_of(alice, id), U256::from(70u64));
 }
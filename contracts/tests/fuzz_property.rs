@@ -1,92 +1,255 @@
-use openzeppelin_stylus::token::erc6909::{
-    error::Error,
-    traits::{IErc6909Supply, IErc6909MetadataUri, IErc6909Enumerable, IErc6909Mintable, IErc6909Burnable},
-    extensions::{
-        token_supply::Erc6909Supply,
-        metadata_uri::Erc6909MetadataUri,
-        enumerable::Erc6909Enumerable,
+use alloy_primitives::{Address, U256};
+use motsu::prelude::Contract;
+use openzeppelin_stylus::{
+    access::control::IAccessControl,
+    token::erc6909::{
+        composite_token::MyToken,
+        traits::{
+            IErc6909, IErc6909Burnable, IErc6909ContentUri, IErc6909Enumerable,
+            IErc6909MetadataUri, IErc6909Mintable, IErc6909Supply,
+        },
+        MINTER_ROLE,
     },
 };
-
-use stylus_sdk::testing::TestVM;
-use alloy_primitives::{Address, U256};
-
 use proptest::prelude::*;
 
-proptest! {
-    /// Randomized property test for the Supply extension.
-    #[motsu::test]
-    fn supply_mint_burn_roundtrip(id in any::<u64>(), amt in any::<u64>()) {
-        let vm = TestVM::default();
+/// Deploys a [`MyToken`] with `admin` as its admin and minter.
+fn deploy(admin: Address) -> Contract<MyToken> {
+    let contract = Contract::<MyToken>::new();
+    contract.sender(admin).constructor(
+        String::from("Token"),
+        String::from("TKN"),
+        String::from("https://example.com/contract.json"),
+        String::from("https://example.com/{id}.json"),
+        admin,
+    );
+    contract.sender(admin).grant_role(MINTER_ROLE.into(), admin).unwrap();
+    contract
+}
+
+/// Randomized property test for the Supply extension.
+#[motsu::test]
+fn supply_mint_burn_roundtrip() {
+    proptest!(|(id in any::<u64>(), amt in any::<u64>(), alice: Address)| {
+        prop_assume!(!alice.is_zero());
+        let contract = deploy(alice);
+        let mut contract = contract.sender(alice);
         let id = U256::from(id);
-        let owner = Address::new([0xAA; 20]);
 
         // 1) Mint
-        let mut sup = Erc6909Supply::from(&vm);
-        sup.mint(owner, owner, id, U256::from(amt)).unwrap();
-        prop_assert_eq!(sup.total_supply(id), U256::from(amt));
+        contract.mint(alice, id, U256::from(amt)).unwrap();
+        prop_assert_eq!(contract.total_supply(id), U256::from(amt));
 
         // 2) Burn the same amount
-        sup.burn(owner, owner, id, U256::from(amt)).unwrap();
-        prop_assert_eq!(sup.total_supply(id), U256::ZERO);
-    }
-
-    /// Randomized property test for Metadata URI round-trip.
-    #[motsu::test]
-    fn metadata_uri_roundtrip(id in any::<u64>()) {
-        let vm = TestVM::default();
+        contract.burn(alice, id, U256::from(amt)).unwrap();
+        prop_assert_eq!(contract.total_supply(id), U256::ZERO);
+    });
+}
+
+/// Randomized property test for Metadata URI round-trip.
+#[motsu::test]
+fn metadata_uri_roundtrip() {
+    proptest!(|(id in any::<u64>(), alice: Address)| {
+        prop_assume!(!alice.is_zero());
+        let contract = Contract::<MyToken>::new();
+        let mut contract = contract.sender(alice);
         let id = U256::from(id);
-        let recipient = Address::new([0xBB; 20]);
-        let uri_bytes = b"https://example.com/".iter()
-            .chain(id.to_string().as_bytes())
-            .cloned()
-            .collect::<Vec<u8>>();
-
-        let mut meta = Erc6909MetadataUri::from(&vm);
-        prop_assert_eq!(meta.set_token_uri(recipient, id, uri_bytes.clone()), Ok(true));
-        prop_assert_eq!(meta.token_uri(id), uri_bytes);
-    }
-
-    /// Randomized property test for Enumerable extension.
-    #[motsu::test]
-    fn enumerable_tracks_ids(ids in prop::collection::vec(any::<u64>(), 1..10)) {
-        let vm = TestVM::default();
-        let mut en = Erc6909Enumerable::from(&vm);
-
-        // insert each unique
-        let mut seen = std::collections::BTreeSet::new();
+        let base_uri = String::from("https://example.com/{id}.json");
+        let uri = format!("https://example.com/{id}.json");
+
+        contract
+            .constructor(
+                String::from("Token"),
+                String::from("TKN"),
+                String::from("https://example.com/contract.json"),
+                base_uri.clone(),
+                alice,
+            );
+        prop_assert_eq!(contract.token_uri(id), base_uri);
+
+        contract.set_token_uri(id, uri.clone()).unwrap();
+        prop_assert_eq!(contract.token_uri(id), uri);
+    });
+}
+
+/// Randomized property test for Enumerable extension.
+#[motsu::test]
+fn enumerable_tracks_ids() {
+    proptest!(|(ids in prop::collection::vec(any::<u64>(), 1..10), alice: Address)| {
+        prop_assume!(!alice.is_zero());
+        let contract = deploy(alice);
+        let mut contract = contract.sender(alice);
+
+        // mint each id, remembering first-seen order
+        let mut seen = Vec::new();
         for raw in &ids {
             let id = U256::from(*raw);
-            en._record_id(id);
-            seen.insert(id);
+            contract.mint(alice, id, U256::ONE).unwrap();
+            if !seen.contains(&id) {
+                seen.push(id);
+            }
         }
 
         // total_ids must match unique count
-        prop_assert_eq!(en.total_ids(), U256::from(seen.len()));
+        prop_assert_eq!(contract.total_ids(), U256::from(seen.len()));
 
         // and id_by_index must return each in insertion order
         for (i, &id) in seen.iter().enumerate() {
-            prop_assert_eq!(en.id_by_index(U256::from(i as u64)), id);
+            prop_assert_eq!(contract.id_by_index(U256::from(i)).unwrap(), id);
         }
-    }
+    });
+}
 
-    #[motsu::test]
-    fn mint_burn_roundtrip(id in any::<u64>(), amt in 0u64..1_000u64, owner_bytes in any::<[u8;20]>()) {
-        let vm    = TestVM::default();
-        let mut sup = Erc6909Supply::from(&vm);
+#[motsu::test]
+fn mint_burn_roundtrip() {
+    proptest!(|(id in any::<u64>(), amt in 0u64..1_000u64, owner: Address)| {
+        prop_assume!(!owner.is_zero());
+        let contract = deploy(owner);
+        let mut contract = contract.sender(owner);
 
         let id = U256::from(id);
         let amt = U256::from(amt);
-        let owner = alloy_primitives::Address::from(owner_bytes);
 
         // start from zero
-        prop_assume!(sup.total_supply(id) == U256::ZERO);
+        prop_assume!(contract.total_supply(id) == U256::ZERO);
 
         // mint then burn
-        sup.mint(owner, owner, id, amt).unwrap();
-        sup.burn(owner, owner, id, amt).unwrap();
+        contract.mint(owner, id, amt).unwrap();
+        contract.burn(owner, id, amt).unwrap();
 
         // total_supply should be zero again
-        prop_assert_eq!(sup.total_supply(id), U256::ZERO);
-    }
+        prop_assert_eq!(contract.total_supply(id), U256::ZERO);
+    });
+}
+
+/// A single step of [`total_supply_equals_sum_of_balances`] and
+/// [`supplies_and_holders_are_conserved_across_ids`], acting on one of
+/// a fixed set of accounts.
+#[derive(Debug, Clone)]
+enum Op {
+    Mint { to: usize, amount: u64 },
+    Transfer { from: usize, to: usize, amount: u64 },
+    Burn { from: usize, amount: u64 },
+}
+
+fn op_strategy(accounts: usize) -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..accounts, any::<u64>())
+            .prop_map(|(to, amount)| Op::Mint { to, amount }),
+        (0..accounts, 0..accounts, any::<u64>())
+            .prop_map(|(from, to, amount)| Op::Transfer { from, to, amount }),
+        (0..accounts, any::<u64>())
+            .prop_map(|(from, amount)| Op::Burn { from, amount }),
+    ]
+}
+
+/// Randomized property test: whatever sequence of mints, transfers and burns
+/// is applied (failing ones included), the total supply of an id always
+/// equals the sum of its balances.
+#[motsu::test]
+fn total_supply_equals_sum_of_balances() {
+    proptest!(|(
+        id in any::<u64>(),
+        accounts in prop::array::uniform3(any::<Address>()),
+        ops in prop::collection::vec(op_strategy(3), 1..20),
+    )| {
+        prop_assume!(accounts.iter().all(|account| !account.is_zero()));
+        prop_assume!(
+            accounts[0] != accounts[1]
+                && accounts[1] != accounts[2]
+                && accounts[0] != accounts[2]
+        );
+        let contract = deploy(accounts[0]);
+        let id = U256::from(id);
+
+        for op in ops {
+            // Failing operations must leave the ledger untouched, so their
+            // results are deliberately ignored.
+            let _ = match op {
+                Op::Mint { to, amount } => contract
+                    .sender(accounts[0])
+                    .mint(accounts[to], id, U256::from(amount)),
+                Op::Transfer { from, to, amount } => contract
+                    .sender(accounts[from])
+                    .transfer(accounts[to], id, U256::from(amount))
+                    .map(|_| ()),
+                Op::Burn { from, amount } => contract
+                    .sender(accounts[from])
+                    .burn(accounts[from], id, U256::from(amount)),
+            };
+
+            let sum = accounts.iter().fold(U256::ZERO, |sum, &account| {
+                sum + contract.sender(accounts[0]).balance_of(account, id)
+            });
+            prop_assert_eq!(contract.sender(accounts[0]).total_supply(id), sum);
+        }
+    });
+}
+
+/// Randomized property test: whatever sequence of mints, transfers and burns
+/// is applied over several ids, supplies and holders counts are conserved.
+/// The supply of each id equals the sum of its balances, the total supply of
+/// all ids equals the sum of the per-id supplies, and the holders count of an
+/// id equals the number of accounts with a non-zero balance of it.
+#[motsu::test]
+fn supplies_and_holders_are_conserved_across_ids() {
+    proptest!(|(
+        ids in prop::array::uniform2(any::<u64>()),
+        accounts in prop::array::uniform3(any::<Address>()),
+        ops in prop::collection::vec((0..2usize, op_strategy(3)), 1..20),
+    )| {
+        prop_assume!(ids[0] != ids[1]);
+        prop_assume!(accounts.iter().all(|account| !account.is_zero()));
+        prop_assume!(
+            accounts[0] != accounts[1]
+                && accounts[1] != accounts[2]
+                && accounts[0] != accounts[2]
+        );
+        let contract = deploy(accounts[0]);
+        let ids = ids.map(U256::from);
+
+        for (id, op) in ops {
+            let id = ids[id];
+            // Failing operations must leave the ledger untouched, so their
+            // results are deliberately ignored.
+            let _ = match op {
+                Op::Mint { to, amount } => contract
+                    .sender(accounts[0])
+                    .mint(accounts[to], id, U256::from(amount)),
+                Op::Transfer { from, to, amount } => contract
+                    .sender(accounts[from])
+                    .transfer(accounts[to], id, U256::from(amount))
+                    .map(|_| ()),
+                Op::Burn { from, amount } => contract
+                    .sender(accounts[from])
+                    .burn(accounts[from], id, U256::from(amount)),
+            };
+
+            let mut total_supply_all = U256::ZERO;
+            for id in ids {
+                let balances: Vec<U256> = accounts
+                    .iter()
+                    .map(|&account| {
+                        contract.sender(accounts[0]).balance_of(account, id)
+                    })
+                    .collect();
+                let supply = contract.sender(accounts[0]).total_supply(id);
+                let holders = balances
+                    .iter()
+                    .filter(|balance| !balance.is_zero())
+                    .count();
+
+                prop_assert_eq!(supply, balances.iter().sum::<U256>());
+                prop_assert_eq!(
+                    contract.sender(accounts[0]).holders_count(id),
+                    U256::from(holders)
+                );
+                total_supply_all += supply;
+            }
+            prop_assert_eq!(
+                contract.sender(accounts[0]).total_supply_all(),
+                total_supply_all
+            );
+        }
+    });
 }
@@ -1,67 +1,365 @@
-#![no_std]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+extern crate alloc;
 
-use stylus_sdk::{entrypoint, storage};
-use alloy_primitives::{Address, U256};
-use openzeppelin_stylus::token::erc6909::{
-    Erc6909Supply, IErc6909, IErc6909Supply, Error,
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use openzeppelin_stylus::{
+    access::ownable::{self, IOwnable, Ownable, OwnableUnauthorizedAccount},
+    token::erc6909::{
+        self, error, extensions::permit, Erc6909, Erc6909OwnableMintable,
+        Erc6909Pausable, Erc6909Permit, Erc6909Supply, Erc6909Update, IErc6909,
+        IErc6909Mintable, IErc6909OperatorSignature, IErc6909Permit,
+        IErc6909Supply,
+    },
+    utils::{
+        cryptography::eip712::IEip712,
+        introspection::erc165::IErc165,
+        nonces::{INonces, Nonces},
+        pausable::{self, IPausable, Pausable},
+    },
 };
+use stylus_sdk::{abi::Bytes, msg, prelude::*};
+
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    InsufficientBalance(error::ERC6909InsufficientBalance),
+    InsufficientAllowance(error::ERC6909InsufficientAllowance),
+    InvalidApprover(error::ERC6909InvalidApprover),
+    InvalidReceiver(error::ERC6909InvalidReceiver),
+    InvalidSender(error::ERC6909InvalidSender),
+    InvalidSpender(error::ERC6909InvalidSpender),
+    InvalidArrayLength(error::ERC6909InvalidArrayLength),
+    FailedDecreaseAllowance(error::ERC6909FailedDecreaseAllowance),
+    UnauthorizedAccount(ownable::OwnableUnauthorizedAccount),
+    InvalidOwner(ownable::OwnableInvalidOwner),
+    EnforcedPause(pausable::EnforcedPause),
+    ExpectedPause(pausable::ExpectedPause),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::FailedDecreaseAllowance(e) => {
+                Error::FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl From<ownable::Error> for Error {
+    fn from(value: ownable::Error) -> Self {
+        match value {
+            ownable::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            ownable::Error::InvalidOwner(e) => Error::InvalidOwner(e),
+        }
+    }
+}
+
+impl From<pausable::Error> for Error {
+    fn from(value: pausable::Error) -> Self {
+        match value {
+            pausable::Error::EnforcedPause(e) => Error::EnforcedPause(e),
+            pausable::Error::ExpectedPause(e) => Error::ExpectedPause(e),
+        }
+    }
+}
 
 #[entrypoint]
 #[storage]
 pub struct MultiToken {
     token: Erc6909Supply,
+    ownable: Ownable,
+    nonces: Nonces,
+    permit: Erc6909Permit<Eip712>,
+    pausable: Pausable,
+}
+
+#[storage]
+struct Eip712;
+
+impl IEip712 for Eip712 {
+    const NAME: &'static str = "ERC-6909 Example";
+    const VERSION: &'static str = "1";
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Mintable<Error = Error>,
+    IErc6909Supply,
+    IOwnable<Error = Error>,
+    INonces,
+    IErc6909Permit<Error = permit::Error>,
+    IErc6909OperatorSignature<Error = permit::Error>,
+    IPausable,
+    IErc165
+)]
+impl MultiToken {
+    #[constructor]
+    fn constructor(&mut self, initial_owner: Address) -> Result<(), Error> {
+        Ok(self.ownable.constructor(initial_owner)?)
+    }
+
+    fn pause(&mut self) -> Result<(), Error> {
+        self._pause(msg::sender())
+    }
+
+    fn unpause(&mut self) -> Result<(), Error> {
+        self._unpause(msg::sender())
+    }
 }
 
+#[public]
 impl IErc6909 for MultiToken {
+    type Error = Error;
+
     fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.token.base.balance_of(owner, id)
+        self.token.balance_of(owner, id)
     }
+
     fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.token.base.allowance(owner, spender, id)
+        self.token.allowance(owner, spender, id)
     }
-    fn is_operator(&self, owner: Address, operator: Address) -> bool {
-        self.token.base.is_operator(owner, operator)
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.token.is_operator(owner, spender)
     }
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self._transfer(msg::sender(), receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self._transfer_from(msg::sender(), sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
     fn approve(
         &mut self,
-        caller: Address,
         spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        self.token.base.approve(caller, spender, id, amount)
+    ) -> Result<bool, Self::Error> {
+        Ok(self.token.approve(spender, id, amount)?)
     }
+
     fn set_operator(
         &mut self,
-        caller: Address,
-        operator: Address,
+        spender: Address,
         approved: bool,
-    ) -> Result<bool, Error> {
-        self.token.base.set_operator(caller, operator, approved)
+    ) -> Result<bool, Self::Error> {
+        Ok(self.token.set_operator(spender, approved)?)
     }
-    fn transfer(
+}
+
+#[public]
+impl IErc6909Mintable for MultiToken {
+    type Error = Error;
+
+    fn mint(
         &mut self,
-        caller: Address,
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        self.token.base.transfer(caller, to, id, amount)
+    ) -> Result<(), Self::Error> {
+        self._authorized_mint(msg::sender(), to, id, amount)
     }
-    fn transfer_from(
+}
+
+#[public]
+impl IErc6909Supply for MultiToken {
+    fn total_supply(&self, id: U256) -> U256 {
+        self.token.total_supply(id)
+    }
+
+    fn total_supply_all(&self) -> U256 {
+        self.token.total_supply_all()
+    }
+
+    fn holders_count(&self, id: U256) -> U256 {
+        self.token.holders_count(id)
+    }
+}
+
+#[public]
+impl IOwnable for MultiToken {
+    type Error = Error;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn transfer_ownership(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<(), Self::Error> {
+        Ok(self.ownable.transfer_ownership(new_owner)?)
+    }
+
+    fn renounce_ownership(&mut self) -> Result<(), Self::Error> {
+        Ok(self.ownable.renounce_ownership()?)
+    }
+}
+
+#[public]
+impl INonces for MultiToken {
+    fn nonces(&self, owner: Address) -> U256 {
+        self.nonces.nonces(owner)
+    }
+}
+
+#[public]
+impl IErc6909Permit for MultiToken {
+    type Error = permit::Error;
+
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    fn domain_separator(&self) -> B256 {
+        self.permit.domain_separator()
+    }
+
+    fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), Self::Error> {
+        self.permit.permit(
+            owner,
+            spender,
+            id,
+            amount,
+            deadline,
+            v,
+            r,
+            s,
+            &mut self.token.erc6909,
+            &mut self.nonces,
+        )
+    }
+}
+
+#[public]
+impl IErc6909OperatorSignature for MultiToken {
+    type Error = permit::Error;
+
+    fn set_operator_by_sig(
+        &mut self,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<bool, Self::Error> {
+        self.permit.set_operator_by_sig(
+            owner,
+            operator,
+            approved,
+            deadline,
+            &signature,
+            &mut self.token.erc6909,
+            &mut self.nonces,
+        )
+    }
+}
+
+#[public]
+impl IPausable for MultiToken {
+    fn paused(&self) -> bool {
+        self.pausable.paused()
+    }
+}
+
+#[public]
+impl IErc165 for MultiToken {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909>::interface_id() == interface_id
+            || <Self as IErc6909Supply>::interface_id() == interface_id
+            || <Self as IErc6909Mintable>::interface_id() == interface_id
+            || <Self as IErc6909Permit>::interface_id() == interface_id
+            || <Self as IErc6909OperatorSignature>::interface_id()
+                == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+            || self.ownable.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Update for MultiToken {
+    type Error = Error;
+
+    fn erc6909(&self) -> &Erc6909 {
+        &self.token.erc6909
+    }
+
+    fn erc6909_mut(&mut self) -> &mut Erc6909 {
+        &mut self.token.erc6909
+    }
+
+    fn _update(
         &mut self,
-        caller: Address,
         from: Address,
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Error> {
-        self.token.base.transfer_from(caller, from, to, id, amount)
+    ) -> Result<(), Error> {
+        self._check_not_paused()?;
+        Ok(self.token._update(from, to, id, amount)?)
     }
 }
 
-impl IErc6909Supply for MultiToken {
-    fn total_supply(&self, id: U256) -> U256 {
-        self.token.total_supply(id)
+impl Erc6909OwnableMintable for MultiToken {
+    fn ownable(&self) -> &Ownable {
+        &self.ownable
+    }
+}
+
+impl Erc6909Pausable for MultiToken {
+    fn pausable(&self) -> &Pausable {
+        &self.pausable
+    }
+
+    fn pausable_mut(&mut self) -> &mut Pausable {
+        &mut self.pausable
+    }
+
+    fn _check_pauser(&self, account: Address) -> Result<(), Error> {
+        if self.ownable.owner() != account {
+            return Err(Error::UnauthorizedAccount(
+                OwnableUnauthorizedAccount { account },
+            ));
+        }
+        Ok(())
     }
 }
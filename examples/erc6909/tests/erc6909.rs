@@ -1,24 +1,56 @@
-use motsu::prelude::*;
 use alloy_primitives::{Address, U256};
-use openzeppelin_stylus::token::erc6909::{Erc6909Supply, Error};
+use examples_erc6909::MultiToken;
+use motsu::prelude::Contract;
+use openzeppelin_stylus::{
+    access::ownable::IOwnable,
+    token::erc6909::{
+        Erc6909, Erc6909Supply, IErc6909, IErc6909Allowance, IErc6909Batch,
+        IErc6909Mintable, IErc6909OperatorSignature, IErc6909Permit,
+        IErc6909Supply,
+    },
+    utils::introspection::erc165::IErc165,
+};
 
 #[motsu::test]
-fn mint_and_transfer(env: TestEnv) {
-    let mut tok = Erc6909Supply::default();
-    let alice = Address::repeat_byte(0xAA);
-    let bob   = Address::repeat_byte(0xBB);
+fn mint_and_transfer(
+    contract: Contract<Erc6909Supply>,
+    alice: Address,
+    bob: Address,
+) {
     let id = U256::from(1);
 
     // mint
-    tok.mint(alice, id, 100u8.into()).unwrap();
-    assert_eq!(tok.base.balance_of(alice, id), 100u8.into());
-    assert_eq!(tok.total_supply(id),            100u8.into());
+    contract.sender(alice).mint(alice, id, U256::from(100)).unwrap();
+    assert_eq!(contract.sender(alice).balance_of(alice, id), U256::from(100));
+    assert_eq!(contract.sender(alice).total_supply(id), U256::from(100));
 
     // transfer 40 to Bob
-    tok.base
-        .transfer_from(alice, alice, bob, id, 40u8.into())
-        .unwrap();
-    assert_eq!(tok.base.balance_of(alice, id), 60u8.into());
-    assert_eq!(tok.base.balance_of(bob,   id), 40u8.into());
-    assert_eq!(tok.total_supply(id),            100u8.into());
+    contract.sender(alice).transfer(bob, id, U256::from(40)).unwrap();
+    assert_eq!(contract.sender(alice).balance_of(alice, id), U256::from(60));
+    assert_eq!(contract.sender(alice).balance_of(bob, id), U256::from(40));
+    assert_eq!(contract.sender(alice).total_supply(id), U256::from(100));
+}
+
+#[motsu::test]
+fn supports_interface(contract: Contract<MultiToken>, alice: Address) {
+    let supported = [
+        <MultiToken as IErc6909>::interface_id(),
+        <MultiToken as IErc6909Supply>::interface_id(),
+        <MultiToken as IErc6909Mintable>::interface_id(),
+        <MultiToken as IErc6909Permit>::interface_id(),
+        <MultiToken as IErc6909OperatorSignature>::interface_id(),
+        <MultiToken as IOwnable>::interface_id(),
+        <MultiToken as IErc165>::interface_id(),
+    ];
+    for interface_id in supported {
+        assert!(contract.sender(alice).supports_interface(interface_id));
+    }
+
+    // `MultiToken` exposes neither batch operations nor allowance updates.
+    assert!(!contract
+        .sender(alice)
+        .supports_interface(<Erc6909 as IErc6909Batch>::interface_id()));
+    assert!(!contract
+        .sender(alice)
+        .supports_interface(<Erc6909 as IErc6909Allowance>::interface_id()));
 }